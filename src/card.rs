@@ -1,5 +1,8 @@
+use std::fmt;
+use std::str::FromStr;
 use serde::Serialize;
 use strum_macros::EnumIter;
+use crate::error::ParseCardError;
 
 /// Represents the rank of a playing card.
 ///
@@ -39,7 +42,9 @@ impl Rank {
             Rank::Ten | Rank::Jack | Rank::Queen | Rank::King => vec![10],
         }
     }
+}
 
+impl fmt::Display for Rank {
     /// Converts the rank to its string representation.
     ///
     /// Face cards are represented by their first letter (J, Q, K),
@@ -52,22 +57,23 @@ impl Rank {
     /// assert_eq!(Rank::Ace.to_string(), "A");
     /// assert_eq!(Rank::Ten.to_string(), "10");
     /// ```
-    pub fn to_string(&self) -> String {
-        match self {
-            Rank::Ace => "A".to_string(),
-            Rank::Two => "2".to_string(),
-            Rank::Three => "3".to_string(),
-            Rank::Four => "4".to_string(),
-            Rank::Five => "5".to_string(),
-            Rank::Six => "6".to_string(),
-            Rank::Seven => "7".to_string(),
-            Rank::Eight => "8".to_string(),
-            Rank::Nine => "9".to_string(),
-            Rank::Ten => "10".to_string(),
-            Rank::Jack => "J".to_string(),
-            Rank::Queen => "Q".to_string(),
-            Rank::King => "K".to_string(),
-        }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Rank::Ace => "A",
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "10",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+        };
+        f.write_str(symbol)
     }
 }
 
@@ -84,7 +90,7 @@ pub enum Suit {
     Spades,
 }
 
-impl Suit {
+impl fmt::Display for Suit {
     /// Converts the suit to its Unicode symbol representation.
     ///
     /// # Examples
@@ -94,13 +100,14 @@ impl Suit {
     /// assert_eq!(Suit::Hearts.to_string(), "❤️");
     /// assert_eq!(Suit::Spades.to_string(), "♠️");
     /// ```
-    pub fn to_string(&self) -> String {
-        match self {
-            Suit::Hearts => "❤️".to_string(),
-            Suit::Diamonds => "♦️".to_string(),
-            Suit::Clubs => "♣️".to_string(),
-            Suit::Spades => "♠️".to_string(),
-        }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Suit::Hearts => "❤️",
+            Suit::Diamonds => "♦️",
+            Suit::Clubs => "♣️",
+            Suit::Spades => "♠️",
+        };
+        f.write_str(symbol)
     }
 }
 
@@ -130,6 +137,33 @@ impl Card {
         }
     }
 
+    /// Returns the card in the short notation accepted by its `FromStr`
+    /// implementation, such as `As` or `Td`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::card::{Card, Rank, Suit};
+    /// let card = Card::new(Rank::Ten, Suit::Diamonds);
+    /// assert_eq!(card.notation(), "Td");
+    /// assert_eq!(card.notation().parse::<Card>().unwrap(), card);
+    /// ```
+    pub fn notation(&self) -> String {
+        let rank = match self.rank {
+            Rank::Ten => "T".to_string(),
+            ref rank => rank.to_string(),
+        };
+        let suit = match self.suit {
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+            Suit::Hearts => 'h',
+            Suit::Spades => 's',
+        };
+        format!("{}{}", rank, suit)
+    }
+}
+
+impl fmt::Display for Card {
     /// Returns a string representation of the card combining rank and suit symbols.
     ///
     /// # Examples
@@ -139,8 +173,55 @@ impl Card {
     /// let card = Card::new(Rank::Ace, Suit::Clubs);
     /// assert_eq!(card.to_string(), "A♣️");
     /// ```
-    pub fn to_string(&self) -> String {
-        format!("{}{}", self.rank.to_string(), self.suit.to_string())
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.rank, self.suit)
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    /// Parses a card from short notation: a rank (`A`, `2`-`9`, `T` or `10`,
+    /// `J`, `Q`, `K`) followed by a suit (`c`, `d`, `h`, `s`). Letters may be
+    /// in either case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::card::{Card, Rank, Suit};
+    /// let card: Card = "Kd".parse().unwrap();
+    /// assert_eq!(card, Card::new(Rank::King, Suit::Diamonds));
+    /// assert_eq!("10h".parse::<Card>().unwrap(), Card::new(Rank::Ten, Suit::Hearts));
+    /// assert!("Zs".parse::<Card>().is_err());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || ParseCardError { token: value.to_string() };
+        let token = value.trim().to_uppercase();
+        let suit_char = token.chars().last().ok_or_else(error)?;
+        let rank = match &token[..token.len() - suit_char.len_utf8()] {
+            "A" => Rank::Ace,
+            "2" => Rank::Two,
+            "3" => Rank::Three,
+            "4" => Rank::Four,
+            "5" => Rank::Five,
+            "6" => Rank::Six,
+            "7" => Rank::Seven,
+            "8" => Rank::Eight,
+            "9" => Rank::Nine,
+            "T" | "10" => Rank::Ten,
+            "J" => Rank::Jack,
+            "Q" => Rank::Queen,
+            "K" => Rank::King,
+            _ => return Err(error()),
+        };
+        let suit = match suit_char {
+            'C' => Suit::Clubs,
+            'D' => Suit::Diamonds,
+            'H' => Suit::Hearts,
+            'S' => Suit::Spades,
+            _ => return Err(error()),
+        };
+        Ok(Card::new(rank, suit))
     }
 }
 
@@ -196,4 +277,16 @@ mod tests {
         let card = Card::new(Rank::Ace, Suit::Clubs);
         assert_eq!(card.to_string(), "A♣️");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_card() {
+        assert_eq!("As".parse::<Card>(), Ok(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!("td".parse::<Card>(), Ok(Card::new(Rank::Ten, Suit::Diamonds)));
+        assert_eq!("10C".parse::<Card>(), Ok(Card::new(Rank::Ten, Suit::Clubs)));
+        assert_eq!("8h".parse::<Card>(), Ok(Card::new(Rank::Eight, Suit::Hearts)));
+
+        for token in ["", "A", "1s", "Ax", "Ks2", "♠️"] {
+            assert_eq!(token.parse::<Card>(), Err(ParseCardError { token: token.to_string() }));
+        }
+    }
+}
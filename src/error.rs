@@ -0,0 +1,210 @@
+use std::fmt;
+use crate::game::{GameAction, GamePhase};
+use crate::money::Money;
+
+/// Represents every error a [`Game`](crate::game::Game) operation can return.
+///
+/// Each variant maps to a stable error code through [`EngineError::code`],
+/// so servers can forward errors to clients without parsing messages.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EngineError {
+    /// The operation is not allowed in the current phase of the game
+    WrongPhase { phase: GamePhase },
+    /// The action is not allowed for the hand in its current state
+    IllegalAction { action: GameAction },
+    /// The player does not have enough money for the bet or action
+    InsufficientFunds { required: Money, available: Money },
+    /// The hand index does not refer to the active hand
+    InvalidHandIndex { index: usize },
+    /// The seat does not exist or it is not that seat's turn
+    InvalidSeat { seat: usize },
+    /// The shoe ran out of cards
+    EmptyShoe,
+    /// The bet was refused
+    InvalidBet(BetError),
+    /// The game settings failed validation
+    InvalidSettings(SettingsError),
+}
+
+impl EngineError {
+    /// Returns a stable, machine-readable code for the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::error::EngineError;
+    /// assert_eq!(EngineError::EmptyShoe.code(), "EMPTY_SHOE");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            EngineError::WrongPhase { .. } => "WRONG_PHASE",
+            EngineError::IllegalAction { .. } => "ILLEGAL_ACTION",
+            EngineError::InsufficientFunds { .. } => "INSUFFICIENT_FUNDS",
+            EngineError::InvalidHandIndex { .. } => "INVALID_HAND_INDEX",
+            EngineError::InvalidSeat { .. } => "INVALID_SEAT",
+            EngineError::EmptyShoe => "EMPTY_SHOE",
+            EngineError::InvalidBet(_) => "INVALID_BET",
+            EngineError::InvalidSettings(_) => "INVALID_SETTINGS",
+        }
+    }
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EngineError::WrongPhase { phase } => write!(f, "Not allowed during the {:?} phase", phase),
+            EngineError::IllegalAction { action } => write!(f, "{} is not allowed right now", action),
+            EngineError::InsufficientFunds { required, available } => {
+                write!(f, "You cannot bet more than you have ({} needed, {} available)", required, available)
+            }
+            EngineError::InvalidHandIndex { index } => write!(f, "Hand {} is not the active hand", index),
+            EngineError::InvalidSeat { seat } => write!(f, "Seat {} cannot act right now", seat),
+            EngineError::EmptyShoe => write!(f, "The shoe is out of cards"),
+            EngineError::InvalidBet(error) => write!(f, "{}", error),
+            EngineError::InvalidSettings(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for EngineError {}
+
+impl From<BetError> for EngineError {
+    fn from(error: BetError) -> Self {
+        EngineError::InvalidBet(error)
+    }
+}
+
+impl From<SettingsError> for EngineError {
+    fn from(error: SettingsError) -> Self {
+        EngineError::InvalidSettings(error)
+    }
+}
+
+/// Reasons a bet can be refused by the table rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BetError {
+    /// The bet is zero or negative
+    NotPositive,
+    /// The bet is below the table minimum
+    BelowMinimum { minimum: Money },
+    /// The bet is above the table maximum
+    AboveMaximum { maximum: Money },
+    /// The bet is not a whole multiple of the chip increment
+    OffIncrement { increment: Money },
+    /// The player bet on no spots or on more spots than the table allows
+    SpotCount { maximum: u8 },
+}
+
+impl fmt::Display for BetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BetError::NotPositive => write!(f, "Bet must be greater than zero"),
+            BetError::BelowMinimum { minimum } => write!(f, "Bet is below the table minimum of {}", minimum),
+            BetError::AboveMaximum { maximum } => write!(f, "Bet is above the table maximum of {}", maximum),
+            BetError::OffIncrement { increment } => write!(f, "Bet must be a multiple of {}", increment),
+            BetError::SpotCount { maximum } => write!(f, "Bet on between 1 and {} spots", maximum),
+        }
+    }
+}
+
+/// Returned when a [`Replay`](crate::replay::Replay) command fails against
+/// the game it is replayed on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayError {
+    /// The index of the failed command, counting from 0
+    pub command: usize,
+    /// Why the game refused the command
+    pub error: EngineError,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Command {} could not be replayed: {}", self.command, self.error)
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Returned when text cannot be parsed as a [`Card`](crate::card::Card).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCardError {
+    /// The text that is not a card
+    pub token: String,
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a card, expected notation like As or Td", self.token)
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+/// Reasons [`GameSettings`](crate::game_settings::GameSettings) can fail validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsError {
+    /// A player name is empty
+    EmptyPlayerName,
+    /// The number of seated players is outside 1-7
+    SeatCount,
+    /// The deck count is outside 1-8
+    DeckCount,
+    /// The bet increment is not positive
+    BetIncrement,
+    /// The table minimum is not positive
+    TableMinimum,
+    /// The table maximum is below the table minimum
+    TableMaximum,
+    /// Players may not bet on any spots
+    MaxSpots,
+    /// The cut card penetration is outside 1-100 percent
+    Penetration,
+    /// The breakage chip used to round payouts is not positive
+    BreakageChip,
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            SettingsError::EmptyPlayerName => "Player name cannot be empty",
+            SettingsError::SeatCount => "A table seats between 1 and 7 players",
+            SettingsError::DeckCount => "Deck count must be between 1 and 8",
+            SettingsError::BetIncrement => "Bet increment must be positive",
+            SettingsError::TableMinimum => "Table minimum must be positive",
+            SettingsError::TableMaximum => "Table maximum cannot be below the table minimum",
+            SettingsError::MaxSpots => "Players must be allowed at least one betting spot",
+            SettingsError::Penetration => "Penetration must be between 1 and 100 percent",
+            SettingsError::BreakageChip => "Breakage chip must be positive",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(EngineError::WrongPhase { phase: GamePhase::DealerTurn }.code(), "WRONG_PHASE");
+        assert_eq!(EngineError::IllegalAction { action: GameAction::Split }.code(), "ILLEGAL_ACTION");
+        assert_eq!(EngineError::InsufficientFunds { required: Money::from_major(10), available: Money::from_major(5) }.code(), "INSUFFICIENT_FUNDS");
+        assert_eq!(EngineError::InvalidHandIndex { index: 3 }.code(), "INVALID_HAND_INDEX");
+        assert_eq!(EngineError::InvalidSeat { seat: 7 }.code(), "INVALID_SEAT");
+        assert_eq!(EngineError::EmptyShoe.code(), "EMPTY_SHOE");
+        assert_eq!(EngineError::from(BetError::NotPositive).code(), "INVALID_BET");
+        assert_eq!(EngineError::from(SettingsError::DeckCount).code(), "INVALID_SETTINGS");
+    }
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(
+            EngineError::from(SettingsError::EmptyPlayerName).to_string(),
+            "Player name cannot be empty"
+        );
+        assert_eq!(
+            EngineError::IllegalAction { action: GameAction::Double }.to_string(),
+            "DOUBLE is not allowed right now"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::mpsc::Sender;
+use serde::Serialize;
+use crate::card::Card;
+use crate::game::GameAction;
+use crate::hand::HandOutcome;
+use crate::money::Money;
+
+/// Who a card was dealt to, or whose hand busted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Recipient {
+    /// The hand at `hand_index` in `seat`
+    Seat { seat: usize, hand_index: usize },
+    /// The dealer
+    Dealer,
+}
+
+/// Something that happened at the table, in the order it happened.
+///
+/// Events are sent to every observer registered with
+/// [`Game::add_observer`](crate::game::Game::add_observer) as the game
+/// changes, so front ends can animate each card and tools can follow the
+/// game without diffing [`GameState`](crate::game::GameState) snapshots.
+///
+/// The dealer's hole card is announced by a `CardDealt` event with
+/// `face_up` set to false and no card, just as
+/// [`Game::dealer_hand_view`](crate::game::Game::dealer_hand_view) hides it.
+/// Its value is only sent in `HoleCardRevealed`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum GameEvent {
+    /// A card was dealt from the shoe. `card` is `None` for a card dealt
+    /// face down.
+    CardDealt { recipient: Recipient, card: Option<Card>, face_up: bool },
+    /// A seat placed a bet on a betting spot. Bets placed again before the
+    /// deal replace the seat's earlier bets.
+    BetPlaced { seat: usize, spot: usize, amount: Money },
+    /// A player took an action on a hand
+    ActionTaken { seat: usize, hand_index: usize, action: GameAction },
+    /// A player's hand or the dealer's hand went over 21
+    HandBusted { recipient: Recipient },
+    /// The dealer turned over the hole card
+    HoleCardRevealed { card: Card },
+    /// A hand was settled, with the money returned to the bankroll
+    HandSettled { seat: usize, hand_index: usize, outcome: HandOutcome, returned: Money },
+    /// The shoe was shuffled and a new shoe is in play
+    ShoeReshuffled,
+}
+
+/// Receives the events of a game as they happen.
+///
+/// Closures taking a `&GameEvent` are observers, and so is the sending end
+/// of a channel, which forwards a copy of each event.
+///
+/// # Examples
+///
+/// ```
+/// use blackjack_engine::event::{GameEvent, GameObserver};
+/// struct Counter(usize);
+///
+/// impl GameObserver for Counter {
+///     fn on_event(&mut self, _event: &GameEvent) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let mut counter = Counter(0);
+/// counter.on_event(&GameEvent::ShoeReshuffled);
+/// assert_eq!(counter.0, 1);
+/// ```
+pub trait GameObserver {
+    /// Called with each event, after the game has applied it.
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+impl<F: FnMut(&GameEvent)> GameObserver for F {
+    fn on_event(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
+
+impl GameObserver for Sender<GameEvent> {
+    /// Forwards the event. Events are dropped once the receiver is gone.
+    fn on_event(&mut self, event: &GameEvent) {
+        let _ = self.send(event.clone());
+    }
+}
@@ -1,8 +1,16 @@
+use std::fmt;
+use std::sync::mpsc::{channel, Receiver};
+use rand::RngCore;
 use serde::Serialize;
-use crate::game::GameAction::{Double, Hit, Split, Stand};
-use crate::game::GameState::WaitingToDeal;
-use crate::game_settings::GameSettings;
-use crate::hand::{Hand, HandOutcome};
+use crate::card::{Card, Rank};
+use crate::error::{BetError, EngineError};
+use crate::event::{GameEvent, GameObserver, Recipient};
+use crate::game::GameAction::{DeclineInsurance, DeclineSurrender, Double, EvenMoney, Hit, Insurance, Split, Stand, Surrender};
+use crate::game_settings::{GameSettings, HoleCardRule, SurrenderRule};
+use crate::hand::{DealerHandView, Hand, HandOutcome};
+use crate::history::{ActionRecord, RoundHistory, RoundRecord, SeatRecord};
+use crate::ledger::EntryKind;
+use crate::money::{Money, Rounding};
 use crate::player::Player;
 use crate::shoe::Shoe;
 
@@ -11,22 +19,42 @@ use crate::shoe::Shoe;
 /// The Game struct manages the entire state of a blackjack game, including
 /// players, cards, and game progression. It implements standard casino
 /// blackjack rules and handles all game actions and state transitions.
+///
+/// Players sit in numbered seats, starting from seat 0 at the dealer's left
+/// (first base). Cards are dealt and play moves around the table in seat
+/// order; seats without a bet sit the round out.
 pub struct Game {
     /// Configuration settings for the game
     pub settings: GameSettings,
     /// The shoe containing all cards for the game
     pub shoe: Shoe,
-    /// The main player
-    pub player: Player,
-    /// The dealer
-    pub dealer: Player,
+    /// The seated players, one per seat
+    pub players: Vec<Player>,
+    /// The dealer. Kept private so the hole card can only be read through
+    /// [`Game::privileged_dealer_hand`].
+    dealer: Player,
     /// Current state of the game
     pub state: GameState,
+    /// True when the shoe was reshuffled for the current round
+    shoe_reshuffled: bool,
+    /// True when the shoe was reshuffled after the last deal, so the next
+    /// deal is the first from the new shoe
+    reshuffled_since_deal: bool,
+    /// The current round number, counting from 1
+    round: u64,
+    /// The actions taken so far this round, in order
+    actions: Vec<ActionRecord>,
+    /// The most recent completed rounds
+    history: RoundHistory,
+    /// Observers notified of every game event
+    observers: Vec<Box<dyn GameObserver + Send>>,
 }
 
 impl Game {
     /// Creates a new blackjack game with the specified settings.
     ///
+    /// A player is seated for each name in `GameSettings::player_names`.
+    ///
     /// # Arguments
     ///
     /// * `settings` - Configuration settings for the game
@@ -37,18 +65,104 @@ impl Game {
     /// use blackjack_engine::game::Game;
     /// use blackjack_engine::game_settings::GameSettings;
     /// let settings = GameSettings::default_single_player("Player1".to_string());
-    /// let game = Game::new(settings);
+    /// let game = Game::new(settings).unwrap();
+    /// assert_eq!(game.players[0].name, "Player1");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `EngineError::InvalidSettings` if the settings fail validation.
+    pub fn new(settings: GameSettings) -> Result<Game, EngineError> {
+        let shoe = Shoe::new(settings.deck_count as usize);
+        Game::with_shoe(settings, shoe)
+    }
+
+    /// Creates a new game whose shoe shuffles are determined by `seed`.
+    ///
+    /// Games created with the same settings and seed deal the same cards,
+    /// which makes disputed hands and simulations reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::game::Game;
+    /// use blackjack_engine::game_settings::GameSettings;
+    /// let settings = GameSettings::default_single_player("Player1".to_string());
+    /// let mut first = Game::with_seed(settings.clone(), 42).unwrap();
+    /// let mut second = Game::with_seed(settings, 42).unwrap();
+    /// first.shuffle_shoe().unwrap();
+    /// second.shuffle_shoe().unwrap();
+    /// assert_eq!(first.shoe.cards, second.shoe.cards);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `EngineError::InvalidSettings` if the settings fail validation.
+    pub fn with_seed(settings: GameSettings, seed: u64) -> Result<Game, EngineError> {
+        let shoe = Shoe::with_seed(settings.deck_count as usize, seed);
+        Game::with_shoe(settings, shoe)
+    }
+
+    /// Creates a new game whose shoe shuffles with the given random number
+    /// generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EngineError::InvalidSettings` if the settings fail validation.
+    pub fn with_rng<R: RngCore + Send + 'static>(settings: GameSettings, rng: R) -> Result<Game, EngineError> {
+        let shoe = Shoe::with_rng(settings.deck_count as usize, rng);
+        Game::with_shoe(settings, shoe)
+    }
+
+    /// Returns a [`GameBuilder`] for configuring how the game's shoe is
+    /// created, such as seeding it or stacking it with a scripted card order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::game::Game;
+    /// use blackjack_engine::game_settings::GameSettings;
+    /// use blackjack_engine::shoe::Shoe;
+    /// let settings = GameSettings::default_single_player("Player1".to_string());
+    /// let game = Game::builder(settings)
+    ///     .shoe(Shoe::from_notation("As Kd 8c 8h").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(game.shoe.cards.len(), 4);
     /// ```
-    pub fn new(settings: GameSettings) -> Game {
-        let player = Player::new();
-        let bankroll = player.bank_roll;
-        Game {
-            player,
+    pub fn builder(settings: GameSettings) -> GameBuilder {
+        GameBuilder { settings, shoe: ShoeSource::Random }
+    }
+
+    /// Creates a new game that deals from `shoe`.
+    fn with_shoe(settings: GameSettings, mut shoe: Shoe) -> Result<Game, EngineError> {
+        settings.validate()?;
+        shoe.set_penetration(settings.penetration);
+        shoe.set_mode(settings.shoe_mode);
+        shoe.set_shuffle_procedure(settings.shuffle_procedure.clone());
+        let players: Vec<Player> = settings.player_names.iter().enumerate()
+            .map(|(seat, name)| {
+                let mut player = Player::with_bankroll(settings.starting_bankroll);
+                player.name = name.clone();
+                player.seat = seat;
+                player
+            })
+            .collect();
+        let mut game = Game {
+            players,
             dealer: Player::new(),
-            shoe: Shoe::new(settings.deck_count as usize),
+            shoe,
+            state: GameState::WaitingForBet { seats: Vec::new() },
+            shoe_reshuffled: false,
+            reshuffled_since_deal: false,
+            round: 1,
+            actions: Vec::new(),
+            history: RoundHistory::new(settings.history_limit),
+            observers: Vec::new(),
             settings,
-            state: GameState::WaitingForBet { player_bankroll: bankroll }
-        }
+        };
+        game.state = GameState::WaitingForBet { seats: game.seat_views() };
+        Ok(game)
     }
 
     /// Returns a reference to the current game state.
@@ -56,394 +170,1332 @@ impl Game {
         &self.state
     }
 
-    /// Shuffles all cards in the shoe.
-    pub fn shuffle_shoe(&mut self) {
-        self.shoe.shuffle();
+    /// Returns true if the shoe was reshuffled for the current round, so the
+    /// caller can let the players know a new shoe is in play.
+    ///
+    /// The shoe is reshuffled by [`Game::next_round`] once the cut card has
+    /// come out, at the deal if too few cards remain for the bets placed, or
+    /// on request through [`Game::shuffle_shoe`]. The flag is set as soon as
+    /// the shoe is reshuffled, stays set through the first round dealt from
+    /// the new shoe, and clears when the round after that is dealt.
+    pub fn shoe_reshuffled(&self) -> bool {
+        self.shoe_reshuffled
+    }
+
+    /// Returns the current round number, counting from 1. Ledger entries
+    /// record the round they were made in.
+    pub fn round(&self) -> u64 {
+        self.round
+    }
+
+    /// Returns the most recent completed rounds, oldest first, up to
+    /// [`GameSettings::history_limit`] of them.
+    ///
+    /// A round is recorded as soon as it is complete, with the dealer's
+    /// hand, every seat's settled hands and net result, and the actions
+    /// taken in order.
+    pub fn history(&self) -> &RoundHistory {
+        &self.history
+    }
+
+    /// Registers an observer to be sent every [`GameEvent`] from now on,
+    /// in the order the events happen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use blackjack_engine::event::GameEvent;
+    /// use blackjack_engine::game::Game;
+    /// use blackjack_engine::game_settings::GameSettings;
+    /// use blackjack_engine::money::Money;
+    /// let mut game = Game::new(GameSettings::default_single_player("Player1".to_string())).unwrap();
+    /// let events = Arc::new(Mutex::new(Vec::new()));
+    /// let log = Arc::clone(&events);
+    /// game.add_observer(move |event: &GameEvent| log.lock().unwrap().push(event.clone()));
+    /// game.accept_user_bet(0, &[Money::from_major(10)]).unwrap();
+    /// assert_eq!(
+    ///     events.lock().unwrap()[0],
+    ///     GameEvent::BetPlaced { seat: 0, spot: 0, amount: Money::from_major(10) }
+    /// );
+    /// ```
+    pub fn add_observer<O: GameObserver + Send + 'static>(&mut self, observer: O) {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// Returns a channel that receives every [`GameEvent`] from now on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::event::GameEvent;
+    /// use blackjack_engine::game::Game;
+    /// use blackjack_engine::game_settings::GameSettings;
+    /// let mut game = Game::new(GameSettings::default_single_player("Player1".to_string())).unwrap();
+    /// let events = game.subscribe();
+    /// game.shuffle_shoe().unwrap();
+    /// assert_eq!(events.try_recv(), Ok(GameEvent::ShoeReshuffled));
+    /// ```
+    pub fn subscribe(&mut self) -> Receiver<GameEvent> {
+        let (sender, receiver) = channel();
+        self.add_observer(sender);
+        receiver
+    }
+
+    /// Sends `event` to every observer.
+    fn emit(&mut self, event: GameEvent) {
+        for observer in self.observers.iter_mut() {
+            observer.on_event(&event);
+        }
+    }
+
+    /// Returns the dealer's hand as the players may see it.
+    ///
+    /// The hole card is shown as face down until the dealer's turn begins.
+    pub fn dealer_hand_view(&self) -> DealerHandView {
+        match self.state {
+            GameState::DealerTurn { .. } | GameState::RoundComplete { .. } => {
+                DealerHandView::revealed(&self.dealer.hands[0])
+            }
+            _ => DealerHandView::hidden(&self.dealer.hands[0]),
+        }
+    }
+
+    /// Returns the dealer's full hand, including the hole card.
+    ///
+    /// This is for server-side use only, such as logging or auditing. Its
+    /// contents must never be sent to a client before the dealer's turn.
+    pub fn privileged_dealer_hand(&self) -> &Hand {
+        &self.dealer.hands[0]
+    }
+
+    /// Shuffles all cards in the shoe, then burns
+    /// [`GameSettings::burn_cards`] cards.
+    ///
+    /// The shoe can only be shuffled between rounds, before any cards are dealt.
+    /// Like any other reshuffle, it is reported by [`Game::shoe_reshuffled`]
+    /// and sent to observers as [`GameEvent::ShoeReshuffled`].
+    pub fn shuffle_shoe(&mut self) -> Result<(), EngineError> {
+        match self.state {
+            GameState::WaitingForBet { .. } | GameState::WaitingToDeal { .. } | GameState::RoundComplete { .. } => {
+                self.shoe.shuffle();
+                self.shoe.burn(self.settings.burn_cards as usize);
+                self.shoe_reshuffled = true;
+                self.reshuffled_since_deal = true;
+                self.emit(GameEvent::ShoeReshuffled);
+                Ok(())
+            }
+            _ => Err(self.wrong_phase()),
+        }
+    }
+
+    /// Returns a wrong phase error for the current state.
+    fn wrong_phase(&self) -> EngineError {
+        EngineError::WrongPhase { phase: self.state.phase() }
+    }
+
+    /// Draws the next card from the shoe.
+    fn draw(&mut self) -> Result<Card, EngineError> {
+        self.shoe.draw_card().ok_or(EngineError::EmptyShoe)
+    }
+
+    /// Deals the next card face up to the hand at `hand_index` in `seat`.
+    fn deal_to_player(&mut self, seat: usize, hand_index: usize) -> Result<(), EngineError> {
+        let card = self.draw()?;
+        self.players[seat].add_card_to_hand(card.clone(), hand_index);
+        self.emit(GameEvent::CardDealt { recipient: Recipient::Seat { seat, hand_index }, card: Some(card), face_up: true });
+        Ok(())
+    }
+
+    /// Deals the next card to the dealer, face down if it is the hole card.
+    fn deal_to_dealer(&mut self, face_up: bool) -> Result<(), EngineError> {
+        let card = self.draw()?;
+        self.dealer.add_card_to_hand(card.clone(), 0);
+        // A face down card stays hidden until the hole card is revealed
+        let card = if face_up { Some(card) } else { None };
+        self.emit(GameEvent::CardDealt { recipient: Recipient::Dealer, card, face_up });
+        Ok(())
+    }
+
+    /// Settles the hand at `hand_index` in `seat` with `outcome`, paying
+    /// `returned` back to the player. A hand that is already settled keeps
+    /// its result, so it is paid and announced only once.
+    fn settle_hand(&mut self, seat: usize, hand_index: usize, outcome: HandOutcome, returned: Money) {
+        if self.players[seat].settle_hand(hand_index, outcome.clone(), returned, self.round) {
+            self.emit(GameEvent::HandSettled { seat, hand_index, outcome, returned });
+        }
     }
 
-    /// Processes a player's bet attempt.
+    /// Settles a busted hand at `hand_index` in `seat` as lost.
+    fn bust_hand(&mut self, seat: usize, hand_index: usize) {
+        self.emit(GameEvent::HandBusted { recipient: Recipient::Seat { seat, hand_index } });
+        self.settle_hand(seat, hand_index, HandOutcome::Loss, Money::ZERO);
+    }
+
+    /// Records an action applied to the hand at `hand_index` in `seat`.
+    fn record_action(&mut self, seat: usize, hand_index: usize, action: GameAction) {
+        self.actions.push(ActionRecord { seat, hand_index, action });
+        self.emit(GameEvent::ActionTaken { seat, hand_index, action });
+    }
+
+    /// Returns a snapshot of every seat for the game state.
+    fn seat_views(&self) -> Vec<SeatView> {
+        self.players.iter().map(SeatView::from).collect()
+    }
+
+    /// Processes the bets for the player in `seat`, one per betting spot.
     ///
-    /// Validates that the player has sufficient funds and updates the game
-    /// state accordingly. If the bet is invalid, prints an error message
-    /// and maintains the current state.
+    /// Validates each bet against the table limits and the chip increment,
+    /// and the total against the player's funds, then updates the game state
+    /// accordingly. If any bet is invalid, the state is left unchanged and
+    /// the reason is returned. Bets are accepted until the cards are dealt,
+    /// and new bets from the same seat replace the earlier ones.
     ///
     /// # Arguments
     ///
-    /// * `bet` - The amount the player wants to bet
-    pub fn accept_user_bet(&mut self, bet: f64) {
-        if self.player.bank_roll < bet {
-            println!("You cannot bet more than you have");
-            return;
+    /// * `seat` - The seat placing the bets
+    /// * `bets` - The amount to bet on each spot, at most
+    ///   `GameSettings::max_spots` of them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::error::{BetError, EngineError};
+    /// use blackjack_engine::game::Game;
+    /// use blackjack_engine::game_settings::GameSettings;
+    /// use blackjack_engine::money::Money;
+    /// let mut game = Game::new(GameSettings::default_single_player("Player1".to_string())).unwrap();
+    /// assert_eq!(game.accept_user_bet(0, &[Money::ZERO]), Err(EngineError::InvalidBet(BetError::NotPositive)));
+    /// assert!(game.accept_user_bet(0, &[Money::from_major(100), Money::from_major(50)]).is_ok());
+    /// assert_eq!(game.players[0].hands[1].spot, 1);
+    /// ```
+    pub fn accept_user_bet(&mut self, seat: usize, bets: &[Money]) -> Result<(), EngineError> {
+        if !matches!(self.state, GameState::WaitingForBet { .. } | GameState::WaitingToDeal { .. }) {
+            return Err(self.wrong_phase());
+        }
+        if seat >= self.players.len() {
+            return Err(EngineError::InvalidSeat { seat });
+        }
+        if bets.is_empty() || bets.len() > self.settings.max_spots as usize {
+            return Err(BetError::SpotCount { maximum: self.settings.max_spots }.into());
+        }
+        for &bet in bets {
+            self.validate_bet(bet)?;
+        }
+
+        // Any bets the seat already placed this round count towards its funds
+        let round = self.round;
+        let player = &mut self.players[seat];
+        let required: Money = bets.iter().sum();
+        let available = player.bank_roll() + player.hands.iter().map(|hand| hand.bet).sum::<Money>();
+        if required > available {
+            return Err(EngineError::InsufficientFunds { required, available });
+        }
+        for hand_index in 0..player.hands.len() {
+            let bet = player.hands[hand_index].bet;
+            if bet.is_positive() {
+                player.record(EntryKind::Refund, bet, hand_index, round);
+            }
+        }
+        player.hands = bets.iter().enumerate()
+            .map(|(spot, &bet)| Hand { spot, ..Hand::with_bet(bet) })
+            .collect();
+        for (hand_index, &bet) in bets.iter().enumerate() {
+            player.record(EntryKind::Wager, -bet, hand_index, round);
+        }
+        for (spot, &amount) in bets.iter().enumerate() {
+            self.emit(GameEvent::BetPlaced { seat, spot, amount });
+        }
+        self.state = GameState::WaitingToDeal { seats: self.seat_views() };
+        Ok(())
+    }
+
+    /// Checks a bet against the table limits and chip increment.
+    fn validate_bet(&self, bet: Money) -> Result<(), EngineError> {
+        if !bet.is_positive() {
+            return Err(BetError::NotPositive.into());
+        }
+        if bet < self.settings.table_minimum {
+            return Err(BetError::BelowMinimum { minimum: self.settings.table_minimum }.into());
+        }
+        if bet > self.settings.table_maximum {
+            return Err(BetError::AboveMaximum { maximum: self.settings.table_maximum }.into());
+        }
+        if !bet.is_multiple_of(self.settings.bet_increment) {
+            return Err(BetError::OffIncrement { increment: self.settings.bet_increment }.into());
+        }
+        Ok(())
+    }
+
+    /// Returns an insufficient funds error if the player in `seat` cannot
+    /// cover `amount`.
+    fn check_funds(&self, seat: usize, amount: Money) -> Result<(), EngineError> {
+        let available = self.players[seat].bank_roll();
+        if amount > available {
+            return Err(EngineError::InsufficientFunds { required: amount, available });
         }
-        self.player.bank_roll -= bet;
-        self.player.hands[0].bet = bet;
-        self.state = WaitingToDeal { player_bet: bet, player_bankroll: self.player.bank_roll }
+        Ok(())
     }
 
-    /// Deals the initial two cards to both player and dealer.
+    /// Deals the initial two cards to every betting spot and the dealer.
     ///
     /// This method:
     /// 1. Ensures sufficient cards are available
-    /// 2. Deals alternating cards to player and dealer
-    /// 3. Checks for natural blackjacks
-    /// 4. Updates game state based on initial hands
-    pub fn deal_initial_cards(&mut self) {
-        // Deal two cards to player and dealer
-        self.shoe.ensure_cards_for_players(1);
-        for _ in 0..2 {
-            if let Some(card) = self.shoe.draw_card() {
-                self.player.add_card_to_hand(card, 0);
+    /// 2. Deals one card at a time around the table, spot by spot in seat
+    ///    order, then to the dealer, twice. Under the European no-hole-card
+    ///    rule the dealer only receives the upcard.
+    /// 3. Offers insurance if the dealer's upcard is an Ace
+    /// 4. Otherwise checks for natural blackjacks and updates game state
+    ///
+    /// Cards can only be dealt once a bet has been accepted.
+    pub fn deal_initial_cards(&mut self) -> Result<(), EngineError> {
+        if !matches!(self.state, GameState::WaitingToDeal { .. }) {
+            return Err(self.wrong_phase());
+        }
+
+        // Seats without a bet sit this round out
+        for player in self.players.iter_mut() {
+            player.hands.retain(|hand| hand.bet.is_positive());
+        }
+        let spots: Vec<(usize, usize)> = self.players.iter().enumerate()
+            .flat_map(|(seat, player)| (0..player.hands.len()).map(move |hand_index| (seat, hand_index)))
+            .collect();
+
+        // The previous round's reshuffle is no longer news
+        if !self.reshuffled_since_deal {
+            self.shoe_reshuffled = false;
+        }
+        // A shoe too short to finish the round is reshuffled early
+        if !self.shoe.has_cards_for(spots.len()) {
+            self.reshuffle_shoe();
+        }
+        self.reshuffled_since_deal = false;
+
+        // Deal two cards to each spot and the dealer
+        for round in 0..2 {
+            for &(seat, hand_index) in spots.iter() {
+                self.deal_to_player(seat, hand_index)?;
             }
-            if let Some(card) = self.shoe.draw_card() {
-                self.dealer.add_card_to_hand(card, 0);
+            if round == 1 && self.no_hole_card() {
+                continue;
             }
+            self.deal_to_dealer(round == 0)?;
         }
 
-        // Handle natural blackjacks
-        if self.player.hands[0].is_natural_blackjack() {
-            if self.dealer.hands[0].is_natural_blackjack() {
-                // Push - return bet to player
-                self.player.bank_roll += self.player.hands[0].bet;
-                self.player.hands[0].outcome = Option::from(HandOutcome::Push);
-                self.state = GameState::RoundComplete {
-                    dealer_hand: self.dealer.hands[0].clone(),
-                    player_hands: self.player.hands.clone(),
-                    player_bankroll: self.player.bank_roll
+        let upcard = &self.dealer.hands[0].cards[0];
+
+        // An Ace upcard means the players are offered insurance before the dealer peeks
+        if upcard.rank == Rank::Ace {
+            return self.offer_insurance(0, 0);
+        }
+
+        // Early surrender lets players give up half the bet against a ten
+        // before the dealer peeks, so every seat answers before the peek
+        if self.settings.surrender == SurrenderRule::Early
+            && !self.no_hole_card()
+            && upcard.rank.value()[0] == 10 {
+            return self.offer_surrender(0, 0);
+        }
+
+        self.resolve_naturals()
+    }
+
+    /// Returns the seat and hand index of every hand from (`seat`,
+    /// `hand_index`) onwards, in the order play moves around the table.
+    fn hands_from(&self, seat: usize, hand_index: usize) -> Vec<(usize, usize)> {
+        (seat..self.players.len())
+            .flat_map(|current| {
+                let first = if current == seat { hand_index } else { 0 };
+                (first..self.players[current].hands.len()).map(move |index| (current, index))
+            })
+            .collect()
+    }
+
+    /// Offers insurance on the first open hand from (`seat`, `hand_index`)
+    /// onwards. Each spot answers separately. Once every spot has answered,
+    /// the dealer peeks.
+    fn offer_insurance(&mut self, seat: usize, hand_index: usize) -> Result<(), EngineError> {
+        let next_hand = self.hands_from(seat, hand_index).into_iter()
+            .find(|&(seat, index)| self.players[seat].hands[index].outcome.is_none());
+        match next_hand {
+            Some((seat, index)) => {
+                self.state = GameState::InsuranceOffered {
+                    dealer_hand: DealerHandView::hidden(&self.dealer.hands[0]),
+                    seats: self.seat_views(),
+                    active_seat: seat,
+                    active_hand_index: index,
+                    legal_actions: self.insurance_actions(seat, index),
                 };
-                return;
-            } else {
-                // Player blackjack pays 3:2
-                self.player.bank_roll += self.player.hands[0].bet * 2.5;
-                self.player.hands[0].outcome = Option::from(HandOutcome::Blackjack);
-                self.state = GameState::RoundComplete {
-                    dealer_hand: self.dealer.hands[0].clone(),
-                    player_hands: self.player.hands.clone(),
-                    player_bankroll: self.player.bank_roll
+                Ok(())
+            }
+            None => {
+                if !self.no_hole_card() {
+                    self.settle_insurance();
+                }
+                self.resolve_naturals()
+            }
+        }
+    }
+
+    /// Offers early surrender on the first open hand from (`seat`,
+    /// `hand_index`) onwards, skipping naturals. Each spot answers
+    /// separately. Once every spot has answered, the dealer peeks.
+    fn offer_surrender(&mut self, seat: usize, hand_index: usize) -> Result<(), EngineError> {
+        let next_hand = self.hands_from(seat, hand_index).into_iter().find(|&(seat, index)| {
+            let hand = &self.players[seat].hands[index];
+            hand.outcome.is_none() && !hand.is_natural_blackjack()
+        });
+        match next_hand {
+            Some((seat, index)) => {
+                self.state = GameState::SurrenderOffered {
+                    dealer_hand: DealerHandView::hidden(&self.dealer.hands[0]),
+                    seats: self.seat_views(),
+                    active_seat: seat,
+                    active_hand_index: index,
+                    legal_actions: vec![Surrender, DeclineSurrender],
                 };
-                return;
+                Ok(())
             }
+            None => self.resolve_naturals(),
         }
+    }
 
-        if self.dealer.hands[0].is_natural_blackjack() {
-            self.player.hands[0].outcome = Option::from(HandOutcome::Loss);
-            self.state = GameState::RoundComplete {
-                dealer_hand: self.dealer.hands[0].clone(),
-                player_hands: self.player.hands.clone(),
-                player_bankroll: self.player.bank_roll
-            };
-            return;
+    /// Settles any natural blackjacks once the dealer has peeked at the hole card.
+    ///
+    /// Completes the round if the dealer has a natural, otherwise pays player
+    /// naturals and moves on to the first hand's turn. Without a hole card
+    /// there is nothing to peek at, so player naturals wait for the dealer's
+    /// second card.
+    fn resolve_naturals(&mut self) -> Result<(), EngineError> {
+        if !self.no_hole_card() && self.peek() {
+            return Ok(());
         }
+        self.next_turn(0, 0)
+    }
 
-        // No blackjacks - proceed to player's turn
-        self.state = GameState::PlayerTurn {
-            dealer_hand: self.dealer.hands[0].clone(),
-            player_hands: self.player.hands.clone(),
-            player_bankroll: self.player.bank_roll,
-            active_hand_index: 0,
+    /// Has the dealer check the hole card for a natural.
+    ///
+    /// A dealer natural pushes player naturals, beats every other open hand
+    /// and completes the round, in which case true is returned. Otherwise
+    /// player naturals are paid according to the table's payout ratio.
+    fn peek(&mut self) -> bool {
+        let dealer_natural = self.dealer.hands[0].is_natural_blackjack();
+        let payout = self.settings.blackjack_payout;
+        let rounding = self.settings.payout_rounding;
+        for seat in 0..self.players.len() {
+            for hand_index in 0..self.players[seat].hands.len() {
+                let hand = &self.players[seat].hands[hand_index];
+                if hand.outcome.is_some() {
+                    continue;
+                }
+                let bet = hand.bet;
+                if hand.is_natural_blackjack() {
+                    if dealer_natural {
+                        // Push - return bet to player
+                        self.settle_hand(seat, hand_index, HandOutcome::Push, bet);
+                    } else {
+                        // Player blackjack pays according to the table's payout ratio
+                        let returned = bet + payout.winnings(bet, rounding);
+                        self.settle_hand(seat, hand_index, HandOutcome::Blackjack, returned);
+                    }
+                } else if dealer_natural {
+                    self.settle_hand(seat, hand_index, HandOutcome::Loss, Money::ZERO);
+                }
+            }
         }
+
+        if dealer_natural {
+            self.complete_round();
+        }
+        dealer_natural
     }
 
-    /// Processes a player's action during their turn.
+    /// Processes a response to an insurance offer on the hand at
+    /// `hand_index` in `seat`.
     ///
-    /// # Arguments
+    /// * `Insurance(amount)` - Places a side bet of up to half the main bet
+    /// * `DeclineInsurance` - Plays on without insurance
+    /// * `EvenMoney` - With a natural, takes an immediate 1:1 payout
     ///
-    /// * `action` - The action chosen by the player (Hit, Stand, Double, or Split)
-    /// * `hand_index` - Index of the hand being played (relevant for split hands)
+    /// Under early surrender the player may also `Surrender` before the peek,
+    /// unless the hand is a natural.
     ///
-    /// Handles all possible player actions including:
-    /// - Hit: Draw another card
-    /// - Stand: End turn for current hand
-    /// - Double: Double bet and take one card
-    /// - Split: Split matching cards into two hands
-    pub fn process_player_action(&mut self, action: GameAction, hand_index: usize) {
+    /// The action must already have passed [`Game::check_action`]. The offer
+    /// then moves to the next spot.
+    fn process_insurance_action(&mut self, seat: usize, hand_index: usize, action: GameAction) -> Result<(), EngineError> {
         match action {
-            Hit => {
-                if let Some(card) = self.shoe.draw_card() {
-                    self.player.add_card_to_hand(card, hand_index);
-                    if self.player.hands[hand_index].is_busted() {
-                        self.player.hands[hand_index].outcome = Option::from(HandOutcome::Loss);
-                        if self.player.hands.len() > hand_index + 1 {
-                            // If there is another hand, it was split and needs at least one
-                            // more card
-                            if let Some(card) = self.shoe.draw_card() {
-                                self.player.add_card_to_hand(card, hand_index + 1);
-                            }
-                            self.state = GameState::PlayerTurn {
-                                dealer_hand: self.dealer.hands[0].clone(),
-                                player_hands: self.player.hands.clone(),
-                                player_bankroll: self.player.bank_roll,
-                                active_hand_index: hand_index + 1
-                            };
-                            return;
-                        }
-                        self.state = GameState::RoundComplete {
-                            dealer_hand: self.dealer.hands[0].clone(),
-                            player_hands: self.player.hands.clone(),
-                            player_bankroll: self.player.bank_roll
-                        };
-                        return;
-                    }
-
-                    if self.player.hands[hand_index].is_blackjack() {
-                        if self.player.hands.len() > hand_index + 1 {
-                            // If there is another hand, it was split and needs at least one
-                            // more card
-                            if let Some(card) = self.shoe.draw_card() {
-                                self.player.add_card_to_hand(card, hand_index + 1);
-                            }
-                            self.state = GameState::PlayerTurn {
-                                dealer_hand: self.dealer.hands[0].clone(),
-                                player_hands: self.player.hands.clone(),
-                                player_bankroll: self.player.bank_roll,
-                                active_hand_index: hand_index + 1
-                            };
-                            return;
-                        }
-                        self.state = GameState::DealerTurn{
-                            dealer_hand: self.dealer.hands[0].clone(),
-                            player_hands: self.player.hands.clone(),
-                            player_bankroll: self.player.bank_roll
-                        };
-                        return;
-                    }
-
-                    self.state = GameState::PlayerTurn {
-                        dealer_hand: self.dealer.hands[0].clone(),
-                        player_hands: self.player.hands.clone(),
-                        player_bankroll: self.player.bank_roll,
-                        active_hand_index: hand_index
-                    }
-                }
-            },
-            Stand => {
-                if self.player.hands.len() > hand_index + 1 {
-                    // If there is another hand, it was split and needs at least one
-                    // more card
-                    if let Some(card) = self.shoe.draw_card() {
-                        self.player.add_card_to_hand(card, hand_index + 1);
-                    }
-                    self.state = GameState::PlayerTurn {
-                        dealer_hand: self.dealer.hands[0].clone(),
-                        player_hands: self.player.hands.clone(),
-                        player_bankroll: self.player.bank_roll,
-                        active_hand_index: hand_index + 1
-                    };
-                    return;
-                }
-                self.state = GameState::DealerTurn {
-                    dealer_hand: self.dealer.hands[0].clone(),
-                    player_hands: self.player.hands.clone(),
-                    player_bankroll: self.player.bank_roll
-                }
+            Insurance(amount) => {
+                let player = &mut self.players[seat];
+                player.record(EntryKind::Insurance, -amount, hand_index, self.round);
+                player.hands[hand_index].insurance = Some(amount);
             }
-            Double => {
-                if let Some(card) = self.shoe.draw_card() {
-                    self.player.add_card_to_hand(card, hand_index);
-                    self.player.bank_roll -= self.player.hands[hand_index].bet;
-                    self.player.hands[hand_index].bet = self.player.hands[hand_index].bet * 2f64;
-                    if self.player.hands.len() > hand_index + 1 {
-                        if let Some(card) = self.shoe.draw_card() {
-                            self.player.add_card_to_hand(card, hand_index + 1);
-                        }
-                        self.state = GameState::PlayerTurn {
-                            dealer_hand: self.dealer.hands[0].clone(),
-                            player_hands: self.player.hands.clone(),
-                            player_bankroll: self.player.bank_roll,
-                            active_hand_index: hand_index + 1
-                        };
-                        return;
-                    }
-                    self.state = GameState::DealerTurn {
-                        dealer_hand: self.dealer.hands[0].clone(),
-                        player_hands: self.player.hands.clone(),
-                        player_bankroll: self.player.bank_roll
-                    }
-                }
-            },
-            Split => {
-                // Check if we can split (should have exactly 2 equal cards)
-                if self.player.hands[hand_index].cards.len() == 2
-                    && self.player.hands[hand_index].cards[hand_index].rank == self.player.hands[hand_index].cards[1].rank {
-                    // Take second card from first hand
-                    let split_card = self.player.hands[hand_index].cards.pop().unwrap();
-
-                    // Create new hand with the split card and same bet
-                    let new_bet = self.player.hands[hand_index].bet;
-                    self.player.bank_roll -= new_bet;  // Deduct additional bet for new hand
-
-                    // Add second hand with split card at index + 1
-                    let new_hand = Hand::with_card_and_bet(split_card, new_bet);
-                    self.player.hands.insert(hand_index + 1, new_hand);
-
-                    // Draw a card for the first hand only
-                    if let Some(card1) = self.shoe.draw_card() {
-                        self.player.add_card_to_hand(card1, hand_index);
-                        self.state = GameState::PlayerTurn {
-                            dealer_hand: self.dealer.hands[0].clone(),
-                            player_hands: self.player.hands.clone(),
-                            player_bankroll: self.player.bank_roll,
-                            active_hand_index: hand_index
-                        }
-                    }
-                }
+            EvenMoney => {
+                let returned = self.players[seat].hands[hand_index].bet * 2;
+                self.settle_hand(seat, hand_index, HandOutcome::Win, returned);
             }
+            Surrender => self.surrender_hand(seat, hand_index),
+            _ => {}
         }
+        self.offer_insurance(seat, hand_index + 1)
     }
 
-    /// Processes the dealer's turn according to standard casino rules.
+    /// Processes a response to an early surrender offer on the hand at
+    /// `hand_index` in `seat`.
     ///
-    /// The dealer must:
-    /// - Hit on 16 or below
-    /// - Stand on 17 or above
-    /// - Continue until reaching 17+ or busting
-    pub fn next_dealer_turn(&mut self) {
-        match self.state {
-            GameState::DealerTurn { dealer_hand: _, player_hands: _, player_bankroll: _, .. } => {
-                let dealer_value = self.dealer.hands[0].best_value();
-
-                // Dealer must hit on 16 or below
-                if dealer_value <= 16 {
-                    if let Some(card) = self.shoe.draw_card() {
-                        self.dealer.add_card_to_hand(card, 0);
-
-                        // Check if dealer busted
-                        if self.dealer.hands[0].is_busted() {
-                            self.determine_winner_and_complete_round();
-                            return;
-                        }
-
-                        // Continue dealer's turn
-                        self.state = GameState::DealerTurn {
-                            dealer_hand: self.dealer.hands[0].clone(),
-                            player_hands: self.player.hands.clone(),
-                            player_bankroll: self.player.bank_roll
-                        };
-                    }
-                } else {
-                    self.determine_winner_and_complete_round();
+    /// * `Surrender` - Forfeits the hand for half the bet before the peek
+    /// * `DeclineSurrender` - Keeps the hand for the player's turn
+    ///
+    /// The action must already have passed [`Game::check_action`]. The offer
+    /// then moves to the next spot.
+    fn process_surrender_offer(&mut self, seat: usize, hand_index: usize, action: GameAction) -> Result<(), EngineError> {
+        if action == Surrender {
+            self.surrender_hand(seat, hand_index);
+        }
+        self.offer_surrender(seat, hand_index + 1)
+    }
+
+    /// Pays every insurance bet 2:1 if the dealer has a natural.
+    ///
+    /// Once every spot has answered, the dealer peeks at the hole card:
+    /// insurance pays 2:1 if the dealer has a natural and is lost otherwise.
+    /// Without a hole card, insurance is settled when the dealer draws a
+    /// second card.
+    fn settle_insurance(&mut self) {
+        if !self.dealer.hands[0].is_natural_blackjack() {
+            return;
+        }
+        for player in self.players.iter_mut() {
+            for hand_index in 0..player.hands.len() {
+                if let Some(insurance) = player.hands[hand_index].insurance {
+                    player.record(EntryKind::Payout, insurance * 3, hand_index, self.round);
                 }
-            },
-            _ => {
             }
         }
     }
 
-    /// Prepares the game for a new round.
+    /// Returns true if the hand at `hand_index` in `seat` may still be
+    /// surrendered: the rules allow it and it is an untouched, unsplit
+    /// starting hand.
     ///
-    /// Resets all hands and returns to the betting state.
-    pub fn next_round(&mut self) {
-        self.player.reset_hands();
-        self.dealer.reset_hands();
-        self.state = GameState::WaitingForBet { player_bankroll: self.player.bank_roll }
+    /// Without a hole card the dealer never peeks, so there is nothing to
+    /// surrender against and neither rule applies.
+    fn can_surrender(&self, seat: usize, hand_index: usize) -> bool {
+        let allowed = match self.settings.surrender {
+            SurrenderRule::NotAllowed => false,
+            SurrenderRule::Late | SurrenderRule::Early => !self.no_hole_card(),
+        };
+        let hands = &self.players[seat].hands;
+        allowed
+            && !hands[hand_index].is_split
+            && hands[hand_index].cards.len() == 2
     }
 
-    /// Determines the winner(s) and updates player bankroll accordingly.
-    ///
-    /// Compares dealer and player hand values according to standard blackjack rules:
-    /// - Dealer bust: All non-busted player hands win
-    /// - Otherwise: Higher hand value wins
-    /// - Equal values: Push (tie)
-    pub fn determine_winner_and_complete_round(&mut self) {
-        let dealer_hand = &self.dealer.hands[0];
-        let dealer_value = dealer_hand.best_value();
-        for (_, hand) in self.player.hands.iter_mut().enumerate() {
-            let player_value = hand.best_value();
-            let hand_outcome = if hand.is_busted() {
-                HandOutcome::Loss
-            } else if dealer_hand.is_busted() {
-                self.player.bank_roll += hand.bet * 2f64;
-                HandOutcome::Win
-            } else if dealer_value > player_value {
-                HandOutcome::Loss
-            } else if player_value > dealer_value {
-                self.player.bank_roll += hand.bet * 2f64;
-                HandOutcome::Win
-            } else {
-                self.player.bank_roll += hand.bet;
-                HandOutcome::Push
-            };
-            hand.outcome = Option::from(hand_outcome);
+    /// Forfeits the starting hand at `hand_index` in `seat`, returning half
+    /// the bet under the table's payout rounding.
+    fn surrender_hand(&mut self, seat: usize, hand_index: usize) {
+        let rounding = self.settings.payout_rounding;
+        let returned = self.players[seat].hands[hand_index].bet.times_ratio(1, 2, rounding);
+        self.settle_hand(seat, hand_index, HandOutcome::Surrender, returned);
+    }
+
+    /// Moves the game into the player turn state with the given active hand.
+    fn set_player_turn(&mut self, seat: usize, active_hand_index: usize) {
+        self.state = GameState::PlayerTurn {
+            dealer_hand: DealerHandView::hidden(&self.dealer.hands[0]),
+            seats: self.seat_views(),
+            active_seat: seat,
+            active_hand_index,
+            legal_actions: self.turn_actions(seat, active_hand_index),
+        };
+    }
+
+    /// Moves the game into the dealer turn state with the current hands.
+    fn set_dealer_turn(&mut self) {
+        self.reveal_hole_card();
+        self.state = GameState::DealerTurn {
+            dealer_hand: self.dealer.hands[0].clone(),
+            seats: self.seat_views(),
+        };
+    }
+
+    /// Announces the dealer's hole card when it is turned over, as play
+    /// passes from the players to the dealer or the round ends early.
+    fn reveal_hole_card(&mut self) {
+        let revealed = matches!(self.state, GameState::DealerTurn { .. } | GameState::RoundComplete { .. });
+        if revealed || self.no_hole_card() {
+            return;
         }
+        if let Some(card) = self.dealer.hands[0].cards.get(1).cloned() {
+            self.emit(GameEvent::HoleCardRevealed { card });
+        }
+    }
 
+    /// Moves the game into the round complete state with the current hands
+    /// and records the round in the history.
+    fn complete_round(&mut self) {
+        self.reveal_hole_card();
+        let round = self.round;
+        self.history.push(RoundRecord {
+            round,
+            dealer_hand: self.dealer.hands[0].clone(),
+            seats: self.players.iter()
+                .map(|player| SeatRecord {
+                    name: player.name.clone(),
+                    hands: player.hands.clone(),
+                    net: player.round_net(round),
+                })
+                .collect(),
+            actions: std::mem::take(&mut self.actions),
+        });
         self.state = GameState::RoundComplete {
             dealer_hand: self.dealer.hands[0].clone(),
-            player_hands: self.player.hands.clone(),
-            player_bankroll: self.player.bank_roll
+            seats: self.seat_views(),
         };
-        return;
     }
-}
-
-/// Represents possible actions a player can take during their turn.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum GameAction {
-    Hit,
-    Stand,
-    Double,
-    Split
-}
 
-impl GameAction {
-    /// Converts a string input to a GameAction.
-    ///
-    /// # Arguments
-    ///
-    /// * `value` - The string to convert
-    ///
-    /// # Returns
+    /// Starts the turn of the first hand from (`seat`, `hand_index`) onwards
+    /// that has a decision to make, moving spot by spot through each seat.
     ///
-    /// Option<GameAction> - Some(action) if valid input, None if invalid
-    pub fn from_string(value: &str) -> Option<GameAction> {
-        match value.to_lowercase().trim() {
-            "h" | "hit" => Some(Hit),
-            "s" | "stand" => Some(Stand),
-            "d" | "double" => Some(Double),
-            "p" | "split" => Some(Split),
-            _ => None
+    /// A hand split off earlier is dealt its second card when play reaches
+    /// it. Hands already settled, naturals and finished split aces are
+    /// skipped. Once no hand is left, the players' turn is over.
+    fn next_turn(&mut self, seat: usize, hand_index: usize) -> Result<(), EngineError> {
+        for (seat, index) in self.hands_from(seat, hand_index) {
+            // A split hand needs at least one more card
+            if self.players[seat].hands[index].cards.len() < 2 {
+                self.deal_to_player(seat, index)?;
+            }
+            let hand = &self.players[seat].hands[index];
+            let natural = !hand.is_split && hand.is_natural_blackjack();
+            if hand.outcome.is_none() && !natural && !self.split_aces_done(seat, index) {
+                self.set_player_turn(seat, index);
+                return Ok(());
+            }
         }
+        self.end_player_turn();
+        Ok(())
     }
 
-    /// Converts the action to its string representation.
-    pub fn to_string(&self) -> String {
-        match self {
-            Hit => "HIT".to_string(),
-            Stand => "STAND".to_string(),
-            Double => "DOUBLE".to_string(),
-            Split => "SPLIT".to_string(),
-        }
+    /// Returns true if the hand at `hand_index` in `seat` may be split under
+    /// the table rules.
+    ///
+    /// The hand must hold a splittable pair (by rank, or by value when
+    /// `GameSettings::split_by_value` is set), its spot must not already be
+    /// at the maximum number of hands, and split aces may only be split
+    /// again when resplitting aces is allowed.
+    fn can_split_hand(&self, seat: usize, hand_index: usize) -> bool {
+        let hands = &self.players[seat].hands;
+        let hand = &hands[hand_index];
+        let spot_hands = hands.iter().filter(|other| other.spot == hand.spot).count();
+        hand.can_split(self.settings.split_by_value)
+            && spot_hands < self.settings.max_split_hands as usize
+            && (!hand.is_split_aces() || self.settings.resplit_aces)
     }
-}
+
+    /// Returns true if the hand at `hand_index` in `seat` came from split
+    /// aces and, having received its one card, can take no further action.
+    fn split_aces_done(&self, seat: usize, hand_index: usize) -> bool {
+        let hand = &self.players[seat].hands[hand_index];
+        self.settings.split_aces_one_card
+            && hand.is_split_aces()
+            && hand.cards.len() == 2
+            && !self.can_split_hand(seat, hand_index)
+    }
+
+    /// Returns true if the hand at `hand_index` in `seat` may be doubled
+    /// under the table rules.
+    ///
+    /// Only the first two cards may be doubled unless double any time is
+    /// enabled. The total must be allowed by `GameSettings::double_down`, soft
+    /// hands need `double_soft_hands`, and split hands need double after split.
+    fn can_double(&self, seat: usize, hand_index: usize) -> bool {
+        let hand = &self.players[seat].hands[hand_index];
+        let card_count_allowed = hand.cards.len() == 2
+            || (self.settings.double_any_time && hand.cards.len() > 2 && !hand.is_busted());
+        self.can_draw(seat, hand_index)
+            && card_count_allowed
+            && self.settings.double_down.allows_total(hand.best_value())
+            && (self.settings.double_soft_hands || !hand.is_soft())
+            && (!hand.is_split || self.settings.double_after_split)
+    }
+
+    /// Returns true if the hand at `hand_index` in `seat` may still draw
+    /// cards by hitting or doubling.
+    fn can_draw(&self, seat: usize, hand_index: usize) -> bool {
+        !(self.settings.split_aces_one_card && self.players[seat].hands[hand_index].is_split_aces())
+    }
+
+    /// Ends the players' turn, handing play to the dealer if any hand still
+    /// depends on the dealer's cards and settling the round otherwise.
+    fn end_player_turn(&mut self) {
+        if self.has_live_hands() || self.hole_card_pending() {
+            self.set_dealer_turn();
+        } else {
+            self.determine_winner_and_complete_round();
+        }
+    }
+
+    /// Returns true if the table plays without a dealer hole card.
+    fn no_hole_card(&self) -> bool {
+        matches!(self.settings.hole_card, HoleCardRule::NoHoleCard { .. })
+    }
+
+    /// Returns true if the dealer still has to draw a second card under the
+    /// no-hole-card rule because the result could change the settlement:
+    /// a hand is still unsettled, insurance was taken, or original bets only
+    /// would refund doubles and splits against a dealer natural.
+    fn hole_card_pending(&self) -> bool {
+        let HoleCardRule::NoHoleCard { original_bets_only } = self.settings.hole_card else {
+            return false;
+        };
+        self.dealer.hands[0].cards.len() == 1
+            && self.players.iter().flat_map(|player| player.hands.iter()).any(|hand| {
+                (hand.outcome.is_none() && !hand.is_busted())
+                    || hand.insurance.is_some()
+                    || (original_bets_only && (hand.is_split || hand.doubled))
+            })
+    }
+
+    /// Returns true if any hand still needs the dealer to finish drawing to be
+    /// settled, i.e. it is unsettled, not busted and not a natural.
+    fn has_live_hands(&self) -> bool {
+        self.players.iter().flat_map(|player| player.hands.iter()).any(|hand| {
+            hand.outcome.is_none()
+                && !hand.is_busted()
+                && !hand.pays_as_natural(self.settings.split_aces_natural)
+        })
+    }
+
+    /// Returns the actions the player in `seat` may take on the hand at
+    /// `hand_index`.
+    ///
+    /// The list is computed from the current state, the hand's cards, the
+    /// player's bankroll and the table rules, and is empty outside the
+    /// insurance and surrender offers and the player's turn or for a seat or
+    /// hand that is not active. An insurance offer is listed with the largest
+    /// stake the player can place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::game::Game;
+    /// use blackjack_engine::game_settings::GameSettings;
+    /// let game = Game::new(GameSettings::default_single_player("Player1".to_string())).unwrap();
+    /// assert!(game.legal_actions(0, 0).is_empty());
+    /// ```
+    pub fn legal_actions(&self, seat: usize, hand_index: usize) -> Vec<GameAction> {
+        match self.state {
+            GameState::InsuranceOffered { active_seat, active_hand_index, .. }
+                if seat == active_seat && hand_index == active_hand_index => {
+                self.insurance_actions(seat, hand_index)
+            }
+            GameState::SurrenderOffered { active_seat, active_hand_index, .. }
+                if seat == active_seat && hand_index == active_hand_index => {
+                vec![Surrender, DeclineSurrender]
+            }
+            GameState::PlayerTurn { active_seat, active_hand_index, .. }
+                if seat == active_seat && hand_index == active_hand_index => {
+                self.turn_actions(seat, hand_index)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the insurance responses the player in `seat` may give for
+    /// the hand at `hand_index`.
+    fn insurance_actions(&self, seat: usize, hand_index: usize) -> Vec<GameAction> {
+        let player = &self.players[seat];
+        let half_bet = player.hands[hand_index].bet.times_ratio(1, 2, Rounding::Down);
+        let max_insurance = half_bet.min(player.bank_roll());
+        [Insurance(max_insurance), DeclineInsurance, EvenMoney, Surrender]
+            .into_iter()
+            .filter(|action| self.check_insurance_action(seat, *action, hand_index).is_ok())
+            .collect()
+    }
+
+    /// Returns the actions the player in `seat` may take on the active hand.
+    fn turn_actions(&self, seat: usize, hand_index: usize) -> Vec<GameAction> {
+        [Hit, Stand, Double, Split, Surrender]
+            .into_iter()
+            .filter(|action| self.check_turn_action(seat, *action, hand_index).is_ok())
+            .collect()
+    }
+
+    /// Checks whether `action` may be taken by `seat` on the hand at
+    /// `hand_index` in the current state, without changing anything.
+    fn check_action(&self, seat: usize, action: GameAction, hand_index: usize) -> Result<(), EngineError> {
+        match self.state {
+            GameState::InsuranceOffered { active_seat, active_hand_index, .. } => {
+                if seat != active_seat {
+                    return Err(EngineError::InvalidSeat { seat });
+                }
+                if hand_index != active_hand_index {
+                    return Err(EngineError::InvalidHandIndex { index: hand_index });
+                }
+                self.check_insurance_action(seat, action, hand_index)
+            }
+            GameState::SurrenderOffered { active_seat, active_hand_index, .. } => {
+                if seat != active_seat {
+                    return Err(EngineError::InvalidSeat { seat });
+                }
+                if hand_index != active_hand_index {
+                    return Err(EngineError::InvalidHandIndex { index: hand_index });
+                }
+                match action {
+                    Surrender | DeclineSurrender => Ok(()),
+                    _ => Err(EngineError::IllegalAction { action }),
+                }
+            }
+            GameState::PlayerTurn { active_seat, active_hand_index, .. } => {
+                if seat != active_seat {
+                    return Err(EngineError::InvalidSeat { seat });
+                }
+                if hand_index != active_hand_index {
+                    return Err(EngineError::InvalidHandIndex { index: hand_index });
+                }
+                self.check_turn_action(seat, action, hand_index)
+            }
+            _ => Err(self.wrong_phase()),
+        }
+    }
+
+    /// Checks a response to the insurance offer. Only the insurance actions
+    /// (and early surrender on a hand that is not a natural) are accepted,
+    /// and a stake may not exceed half the bet or the player's bankroll.
+    fn check_insurance_action(&self, seat: usize, action: GameAction, hand_index: usize) -> Result<(), EngineError> {
+        let hand = &self.players[seat].hands[hand_index];
+        match action {
+            Insurance(amount) => {
+                if !(amount.is_positive() && amount * 2 <= hand.bet) {
+                    return Err(EngineError::IllegalAction { action });
+                }
+                self.check_funds(seat, amount)
+            }
+            DeclineInsurance => Ok(()),
+            EvenMoney if hand.is_natural_blackjack() => Ok(()),
+            Surrender if self.settings.surrender == SurrenderRule::Early
+                && !hand.is_natural_blackjack()
+                && self.can_surrender(seat, hand_index) => Ok(()),
+            _ => Err(EngineError::IllegalAction { action }),
+        }
+    }
+
+    /// Checks an action on the active hand during the player's turn. Each
+    /// action must be allowed by the table rules and covered by the
+    /// player's bankroll.
+    fn check_turn_action(&self, seat: usize, action: GameAction, hand_index: usize) -> Result<(), EngineError> {
+        let bet = self.players[seat].hands[hand_index].bet;
+        match action {
+            Hit if self.can_draw(seat, hand_index) => Ok(()),
+            Stand => Ok(()),
+            Double if self.can_double(seat, hand_index) => self.check_funds(seat, bet),
+            Split if self.can_split_hand(seat, hand_index) => self.check_funds(seat, bet),
+            Surrender if self.can_surrender(seat, hand_index) => Ok(()),
+            _ => Err(EngineError::IllegalAction { action }),
+        }
+    }
+
+    /// Processes a player's action during their turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat` - The seat taking the action
+    /// * `action` - The action chosen by the player (Hit, Stand, Double, or Split)
+    /// * `hand_index` - Index of the hand being played (relevant for split hands)
+    ///
+    /// Handles all possible player actions including:
+    /// - Hit: Draw another card
+    /// - Stand: End turn for current hand
+    /// - Double: Double bet and take one card, within the table's doubling
+    ///   rules
+    /// - Split: Split matching cards into two hands, subject to the table's
+    ///   resplit and split-aces rules
+    /// - Surrender: Forfeit the starting hand for half the bet back
+    ///
+    /// While insurance or early surrender is offered, only the answers to
+    /// that offer are accepted, and each spot answers in turn before the
+    /// dealer peeks. Every action is recorded for the round's
+    /// [history](Game::history).
+    ///
+    /// # Errors
+    ///
+    /// Returns `WrongPhase` outside the offers and the player turn,
+    /// `InvalidSeat` if it is not `seat`'s turn,
+    /// `InvalidHandIndex` if `hand_index` is not the active hand,
+    /// `IllegalAction` if the rules do not allow the action,
+    /// `InsufficientFunds` if the player cannot cover a double, split or
+    /// insurance bet, and `EmptyShoe` if the shoe runs out of cards.
+    pub fn process_player_action(&mut self, seat: usize, action: GameAction, hand_index: usize) -> Result<(), EngineError> {
+        self.check_action(seat, action, hand_index)?;
+
+        if let GameState::InsuranceOffered { .. } = self.state {
+            self.record_action(seat, hand_index, action);
+            return self.process_insurance_action(seat, hand_index, action);
+        }
+
+        if let GameState::SurrenderOffered { .. } = self.state {
+            self.record_action(seat, hand_index, action);
+            return self.process_surrender_offer(seat, hand_index, action);
+        }
+
+        self.record_action(seat, hand_index, action);
+
+        match action {
+            Hit => {
+                self.deal_to_player(seat, hand_index)?;
+                let hand = &self.players[seat].hands[hand_index];
+                if hand.is_busted() {
+                    self.bust_hand(seat, hand_index);
+                    self.next_turn(seat, hand_index + 1)?;
+                } else if hand.is_blackjack() {
+                    self.next_turn(seat, hand_index + 1)?;
+                } else {
+                    self.set_player_turn(seat, hand_index);
+                }
+            },
+            Stand => self.next_turn(seat, hand_index + 1)?,
+            Double => {
+                self.deal_to_player(seat, hand_index)?;
+                let player = &mut self.players[seat];
+                let bet = player.hands[hand_index].bet;
+                player.record(EntryKind::Double, -bet, hand_index, self.round);
+                player.hands[hand_index].double_bet();
+                if player.hands[hand_index].is_busted() {
+                    self.bust_hand(seat, hand_index);
+                }
+                self.next_turn(seat, hand_index + 1)?;
+            },
+            Insurance(_) | DeclineInsurance | EvenMoney | DeclineSurrender => {}
+            Surrender => {
+                self.surrender_hand(seat, hand_index);
+                self.next_turn(seat, hand_index + 1)?;
+            }
+            Split => {
+                let player = &mut self.players[seat];
+
+                // Take second card from first hand
+                let split_card = player.hands[hand_index].cards.pop().unwrap();
+
+                // Create new hand with the split card and same bet
+                let new_bet = player.hands[hand_index].bet;
+                player.hands[hand_index].is_split = true;
+
+                // Add second hand with split card at index + 1
+                let mut new_hand = Hand::with_card_and_bet(split_card, new_bet);
+                new_hand.is_split = true;
+                new_hand.spot = player.hands[hand_index].spot;
+                player.hands.insert(hand_index + 1, new_hand);
+                // Deduct additional bet for new hand
+                player.record(EntryKind::Split, -new_bet, hand_index + 1, self.round);
+
+                // Draw a card for the first hand only
+                self.deal_to_player(seat, hand_index)?;
+                if self.split_aces_done(seat, hand_index) {
+                    self.next_turn(seat, hand_index + 1)?;
+                } else {
+                    self.set_player_turn(seat, hand_index);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Processes the dealer's turn according to standard casino rules.
+    ///
+    /// Under the no-hole-card rule the dealer first draws a second card; a
+    /// natural then settles every hand at once. After that the dealer must:
+    /// - Hit on 16 or below
+    /// - Hit soft 17 when `GameSettings::dealer_hits_soft_17` is enabled (H17),
+    ///   otherwise stand on all 17s (S17)
+    /// - Stand on 17 or above
+    /// - Continue until reaching 17+ or busting
+    ///
+    /// The dealer stops drawing once no player hand depends on the result.
+    /// Returns `WrongPhase` if it is not the dealer's turn.
+    pub fn next_dealer_turn(&mut self) -> Result<(), EngineError> {
+        if !matches!(self.state, GameState::DealerTurn { .. }) {
+            return Err(self.wrong_phase());
+        }
+
+        if self.dealer.hands[0].cards.len() == 1 {
+            self.deal_to_dealer(true)?;
+            if self.dealer.hands[0].is_natural_blackjack() {
+                self.settle_dealer_natural();
+            } else {
+                self.set_dealer_turn();
+            }
+            return Ok(());
+        }
+
+        if self.has_live_hands() && self.dealer_must_hit() {
+            self.deal_to_dealer(true)?;
+
+            // Check if dealer busted
+            if self.dealer.hands[0].is_busted() {
+                self.emit(GameEvent::HandBusted { recipient: Recipient::Dealer });
+                self.determine_winner_and_complete_round();
+                return Ok(());
+            }
+
+            // Continue dealer's turn
+            self.set_dealer_turn();
+        } else {
+            self.determine_winner_and_complete_round();
+        }
+        Ok(())
+    }
+
+    /// Settles the round when the dealer's second card makes a natural under
+    /// the no-hole-card rule.
+    ///
+    /// Insurance pays 2:1 and a player natural pushes. Every other open hand
+    /// loses, unless the table plays original bets only (OBO), in which case
+    /// the player loses just the original wager and all doubles and split bets
+    /// are refunded. Hands already settled, such as busted, surrendered or
+    /// even money hands, keep their result.
+    fn settle_dealer_natural(&mut self) {
+        self.settle_insurance();
+
+        let original_bets_only = matches!(
+            self.settings.hole_card,
+            HoleCardRule::NoHoleCard { original_bets_only: true }
+        );
+        let split_aces_natural = self.settings.split_aces_natural;
+        for seat in 0..self.players.len() {
+            let mut previous_spot = None;
+            for hand_index in 0..self.players[seat].hands.len() {
+                let hand = &self.players[seat].hands[hand_index];
+                // The first hand on each spot holds the original wager
+                let original = previous_spot != Some(hand.spot);
+                previous_spot = Some(hand.spot);
+                if hand.outcome.is_some() {
+                    continue;
+                }
+                let bet = hand.bet;
+                if hand.pays_as_natural(split_aces_natural) {
+                    self.settle_hand(seat, hand_index, HandOutcome::Push, bet);
+                } else if original_bets_only && !original {
+                    // Split hands were never part of the original wager
+                    self.settle_hand(seat, hand_index, HandOutcome::Push, bet);
+                } else {
+                    // A doubled bet is always an even number of units
+                    let refund = if original_bets_only && hand.doubled {
+                        bet.times_ratio(1, 2, Rounding::Down)
+                    } else {
+                        Money::ZERO
+                    };
+                    self.settle_hand(seat, hand_index, HandOutcome::Loss, refund);
+                }
+            }
+        }
+
+        self.complete_round();
+    }
+
+    /// Returns true if the dealer's current hand requires another card.
+    fn dealer_must_hit(&self) -> bool {
+        let dealer_hand = &self.dealer.hands[0];
+        let dealer_value = dealer_hand.best_value();
+        dealer_value <= 16
+            || (dealer_value == 17 && self.settings.dealer_hits_soft_17 && dealer_hand.is_soft())
+    }
+
+    /// Prepares the game for a new round.
+    ///
+    /// Resets all hands and returns to the betting state. The round's cards
+    /// go to the discard tray, or back into a continuous shuffling machine.
+    /// If the cut card came out during the round, the shoe is reshuffled and
+    /// [`Game::shoe_reshuffled`] reports it. Returns `WrongPhase` unless the
+    /// current round is complete.
+    pub fn next_round(&mut self) -> Result<(), EngineError> {
+        if !matches!(self.state, GameState::RoundComplete { .. }) {
+            return Err(self.wrong_phase());
+        }
+        for player in self.players.iter_mut() {
+            player.reset_hands();
+        }
+        self.dealer.reset_hands();
+        self.shoe.discard_in_play();
+        self.round += 1;
+        if self.shoe.cut_card_reached() {
+            self.reshuffle_shoe();
+        }
+        self.state = GameState::WaitingForBet { seats: self.seat_views() };
+        Ok(())
+    }
+
+    /// Returns all cards to the shoe, shuffles it, burns cards and flags the
+    /// reshuffle.
+    fn reshuffle_shoe(&mut self) {
+        self.shoe.reshuffle();
+        self.shoe.burn(self.settings.burn_cards as usize);
+        self.shoe_reshuffled = true;
+        self.reshuffled_since_deal = true;
+        self.emit(GameEvent::ShoeReshuffled);
+    }
+
+    /// Determines the winner(s) and updates every seat's bankroll accordingly.
+    ///
+    /// Compares dealer and player hand values according to standard blackjack rules:
+    /// - Hands that were already settled (busted or surrendered) are left as is
+    /// - A natural that was not settled at the deal pays blackjack
+    /// - Dealer bust: All non-busted player hands win
+    /// - Otherwise: Higher hand value wins
+    /// - Equal values: Push (tie)
+    fn determine_winner_and_complete_round(&mut self) {
+        let dealer_value = self.dealer.hands[0].best_value();
+        let dealer_busted = self.dealer.hands[0].is_busted();
+        let payout = self.settings.blackjack_payout;
+        let rounding = self.settings.payout_rounding;
+        let split_aces_natural = self.settings.split_aces_natural;
+        for seat in 0..self.players.len() {
+            for hand_index in 0..self.players[seat].hands.len() {
+                let hand = &self.players[seat].hands[hand_index];
+                if hand.outcome.is_some() {
+                    continue;
+                }
+                let bet = hand.bet;
+                let player_value = hand.best_value();
+                let (hand_outcome, returned) = if hand.is_busted() {
+                    (HandOutcome::Loss, Money::ZERO)
+                } else if hand.pays_as_natural(split_aces_natural) {
+                    (HandOutcome::Blackjack, bet + payout.winnings(bet, rounding))
+                } else if dealer_busted {
+                    (HandOutcome::Win, bet * 2)
+                } else if dealer_value > player_value {
+                    (HandOutcome::Loss, Money::ZERO)
+                } else if player_value > dealer_value {
+                    (HandOutcome::Win, bet * 2)
+                } else {
+                    (HandOutcome::Push, bet)
+                };
+                self.settle_hand(seat, hand_index, hand_outcome, returned);
+            }
+        }
+
+        self.complete_round();
+    }
+}
+
+/// Builds a [`Game`] with a custom shoe. Created by [`Game::builder`].
+///
+/// By default the shoe is shuffled from the operating system's entropy, as
+/// with [`Game::new`]. If several shoe options are set, the last one wins.
+pub struct GameBuilder {
+    settings: GameSettings,
+    shoe: ShoeSource,
+}
+
+/// Where a [`GameBuilder`] gets the game's shoe from.
+enum ShoeSource {
+    Random,
+    Seed(u64),
+    Rng(Box<dyn RngCore + Send>),
+    Stacked(Shoe),
+}
+
+impl GameBuilder {
+    /// Shuffles the shoe with a generator seeded from `seed`, as
+    /// [`Game::with_seed`] does.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.shoe = ShoeSource::Seed(seed);
+        self
+    }
+
+    /// Shuffles the shoe with the given random number generator.
+    pub fn rng<R: RngCore + Send + 'static>(mut self, rng: R) -> Self {
+        self.shoe = ShoeSource::Rng(Box::new(rng));
+        self
+    }
+
+    /// Deals from `shoe` instead of a freshly built one, typically a stacked
+    /// shoe from [`Shoe::from_notation`] that scripts every card of a round.
+    pub fn shoe(mut self, shoe: Shoe) -> Self {
+        self.shoe = ShoeSource::Stacked(shoe);
+        self
+    }
+
+    /// Creates the game.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EngineError::InvalidSettings` if the settings fail validation.
+    pub fn build(self) -> Result<Game, EngineError> {
+        let decks = self.settings.deck_count as usize;
+        let shoe = match self.shoe {
+            ShoeSource::Random => Shoe::new(decks),
+            ShoeSource::Seed(seed) => Shoe::with_seed(decks, seed),
+            ShoeSource::Rng(rng) => Shoe::with_rng(decks, rng),
+            ShoeSource::Stacked(shoe) => shoe,
+        };
+        Game::with_shoe(self.settings, shoe)
+    }
+}
+
+/// Represents possible actions a player can take during their turn.
+///
+/// The insurance actions are only accepted while the game is in
+/// [`GameState::InsuranceOffered`], and `DeclineSurrender` only in
+/// [`GameState::SurrenderOffered`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum GameAction {
+    Hit,
+    Stand,
+    Double,
+    Split,
+    /// Forfeit the starting hand and get half the bet back
+    Surrender,
+    /// Take insurance with the given side stake (at most half the main bet)
+    Insurance(Money),
+    /// Decline the insurance offer
+    DeclineInsurance,
+    /// Take an immediate 1:1 payout on a natural instead of risking a push
+    EvenMoney,
+    /// Decline the early surrender offer
+    DeclineSurrender,
+}
+
+impl GameAction {
+    /// Converts a string input to a GameAction.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The string to convert
+    ///
+    /// # Returns
+    ///
+    /// Option<GameAction> - Some(action) if valid input, None if invalid
+    pub fn from_string(value: &str) -> Option<GameAction> {
+        match value.to_lowercase().trim() {
+            "h" | "hit" => Some(Hit),
+            "s" | "stand" => Some(Stand),
+            "d" | "double" => Some(Double),
+            "p" | "split" => Some(Split),
+            "r" | "surrender" => Some(Surrender),
+            "n" | "no insurance" => Some(DeclineInsurance),
+            "e" | "even money" => Some(EvenMoney),
+            "k" | "no surrender" => Some(DeclineSurrender),
+            _ => None
+        }
+    }
+
+}
+
+impl fmt::Display for GameAction {
+    /// Converts the action to its string representation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Hit => "HIT",
+            Stand => "STAND",
+            Double => "DOUBLE",
+            Split => "SPLIT",
+            Surrender => "SURRENDER",
+            Insurance(_) => "INSURANCE",
+            DeclineInsurance => "DECLINE INSURANCE",
+            EvenMoney => "EVEN MONEY",
+            DeclineSurrender => "DECLINE SURRENDER",
+        };
+        f.write_str(label)
+    }
+}
 
 /// Represents the current state of the game.
+///
+/// Every state after the table opens carries a snapshot of each seat.
 #[derive(PartialEq, Clone)]
 pub enum GameState {
-    /// Waiting for player to place initial bet
+    /// Waiting for players to place their bets
     WaitingForBet {
-        player_bankroll: f64,
+        seats: Vec<SeatView>,
     },
-    /// Bet placed, waiting to deal cards
+    /// At least one bet placed, waiting to deal cards. Other seats may still bet.
     WaitingToDeal {
-        player_bet: f64,
-        player_bankroll: f64,
+        seats: Vec<SeatView>,
+    },
+    /// Dealer shows an Ace, the active seat may take insurance or even money
+    InsuranceOffered {
+        dealer_hand: DealerHandView,
+        seats: Vec<SeatView>,
+        active_seat: usize,
+        active_hand_index: usize,
+        legal_actions: Vec<GameAction>,
     },
-    /// Player's turn to act
+    /// Dealer shows a ten under early surrender, the active seat may
+    /// surrender before the peek
+    SurrenderOffered {
+        dealer_hand: DealerHandView,
+        seats: Vec<SeatView>,
+        active_seat: usize,
+        active_hand_index: usize,
+        legal_actions: Vec<GameAction>,
+    },
+    /// The active seat's turn to act
     PlayerTurn {
-        dealer_hand: Hand,
-        player_hands: Vec<Hand>,
-        player_bankroll: f64,
+        dealer_hand: DealerHandView,
+        seats: Vec<SeatView>,
+        active_seat: usize,
         active_hand_index: usize,
+        legal_actions: Vec<GameAction>,
     },
     /// Dealer's turn to act
     DealerTurn {
         dealer_hand: Hand,
-        player_hands: Vec<Hand>,
-        player_bankroll: f64,
+        seats: Vec<SeatView>,
     },
     /// Round is complete, showing results
     RoundComplete {
         dealer_hand: Hand,
-        player_hands: Vec<Hand>,
-        player_bankroll: f64,
+        seats: Vec<SeatView>,
+    }
+}
+
+impl GameState {
+    /// Returns the phase of the game this state belongs to.
+    pub fn phase(&self) -> GamePhase {
+        match self {
+            GameState::WaitingForBet { .. } => GamePhase::WaitingForBet,
+            GameState::WaitingToDeal { .. } => GamePhase::WaitingToDeal,
+            GameState::InsuranceOffered { .. } => GamePhase::InsuranceOffered,
+            GameState::SurrenderOffered { .. } => GamePhase::SurrenderOffered,
+            GameState::PlayerTurn { .. } => GamePhase::PlayerTurn,
+            GameState::DealerTurn { .. } => GamePhase::DealerTurn,
+            GameState::RoundComplete { .. } => GamePhase::RoundComplete,
+        }
+    }
+
+    /// Returns the snapshot of every seat at the table.
+    pub fn seats(&self) -> &[SeatView] {
+        match self {
+            GameState::WaitingForBet { seats }
+            | GameState::WaitingToDeal { seats }
+            | GameState::InsuranceOffered { seats, .. }
+            | GameState::SurrenderOffered { seats, .. }
+            | GameState::PlayerTurn { seats, .. }
+            | GameState::DealerTurn { seats, .. }
+            | GameState::RoundComplete { seats, .. } => seats,
+        }
+    }
+}
+
+/// A snapshot of one seat at the table.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SeatView {
+    /// Name of the seated player
+    pub name: String,
+    /// Player's current bankroll
+    pub bankroll: Money,
+    /// Player's hands (multiple possible due to splits). Empty if the seat
+    /// sat the round out.
+    pub hands: Vec<Hand>,
+}
+
+impl From<&Player> for SeatView {
+    fn from(player: &Player) -> Self {
+        SeatView {
+            name: player.name.clone(),
+            bankroll: player.bank_roll(),
+            hands: player.hands.clone(),
+        }
     }
 }
 
@@ -453,23 +1505,26 @@ pub enum GameState {
 pub struct GameStateDto {
     /// Current phase of the game
     pub phase: GamePhase,
-    /// Player's current bankroll
-    pub player_bankroll: f64,
-    /// Current bet amount, if a bet has been placed
-    pub player_bet: Option<f64>,
-    /// Dealer's hand, if cards have been dealt
-    pub dealer_hand: Option<Hand>,
-    /// Player's hands (multiple possible due to splits)
-    pub player_hands: Option<Vec<Hand>>,
+    /// Every seat at the table with its bankroll and hands
+    pub seats: Vec<SeatView>,
+    /// Dealer's hand, if cards have been dealt. The hole card stays face
+    /// down until the dealer's turn.
+    pub dealer_hand: Option<DealerHandView>,
+    /// Seat whose turn it is (relevant during the offers and player turns)
+    pub active_seat: Option<usize>,
     /// Index of the active hand (relevant during player turns)
     pub active_hand_index: Option<usize>,
+    /// Actions the active seat may take on the active hand
+    pub legal_actions: Vec<GameAction>,
 }
 
 /// Represents the current phase of the game
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub enum GamePhase {
     WaitingForBet,
     WaitingToDeal,
+    InsuranceOffered,
+    SurrenderOffered,
     PlayerTurn,
     DealerTurn,
     RoundComplete,
@@ -477,47 +1532,1540 @@ pub enum GamePhase {
 
 impl From<GameState> for GameStateDto {
     fn from(state: GameState) -> Self {
+        let phase = state.phase();
         match state {
-            GameState::WaitingForBet { player_bankroll } => GameStateDto {
-                phase: GamePhase::WaitingForBet,
-                player_bankroll,
-                player_bet: None,
-                dealer_hand: None,
-                player_hands: None,
-                active_hand_index: None,
-            },
-            GameState::WaitingToDeal { player_bet, player_bankroll } => GameStateDto {
-                phase: GamePhase::WaitingToDeal,
-                player_bankroll,
-                player_bet: Some(player_bet),
+            GameState::WaitingForBet { seats } | GameState::WaitingToDeal { seats } => GameStateDto {
+                phase,
+                seats,
                 dealer_hand: None,
-                player_hands: None,
+                active_seat: None,
                 active_hand_index: None,
+                legal_actions: Vec::new(),
             },
-            GameState::PlayerTurn { dealer_hand, player_hands, player_bankroll, active_hand_index } => GameStateDto {
-                phase: GamePhase::PlayerTurn,
-                player_bankroll,
-                player_bet: player_hands.first().map(|h| h.bet),
+            GameState::InsuranceOffered { dealer_hand, seats, active_seat, active_hand_index, legal_actions }
+            | GameState::SurrenderOffered { dealer_hand, seats, active_seat, active_hand_index, legal_actions }
+            | GameState::PlayerTurn { dealer_hand, seats, active_seat, active_hand_index, legal_actions } => GameStateDto {
+                phase,
+                seats,
                 dealer_hand: Some(dealer_hand),
-                player_hands: Some(player_hands),
+                active_seat: Some(active_seat),
                 active_hand_index: Some(active_hand_index),
+                legal_actions,
             },
-            GameState::DealerTurn { dealer_hand, player_hands, player_bankroll } => GameStateDto {
-                phase: GamePhase::DealerTurn,
-                player_bankroll,
-                player_bet: player_hands.first().map(|h| h.bet),
-                dealer_hand: Some(dealer_hand),
-                player_hands: Some(player_hands),
-                active_hand_index: None,
-            },
-            GameState::RoundComplete { dealer_hand, player_hands, player_bankroll } => GameStateDto {
-                phase: GamePhase::RoundComplete,
-                player_bankroll,
-                player_bet: player_hands.first().map(|h| h.bet),
-                dealer_hand: Some(dealer_hand),
-                player_hands: Some(player_hands),
+            GameState::DealerTurn { dealer_hand, seats } | GameState::RoundComplete { dealer_hand, seats } => GameStateDto {
+                phase,
+                seats,
+                dealer_hand: Some(DealerHandView::revealed(&dealer_hand)),
+                active_seat: None,
                 active_hand_index: None,
+                legal_actions: Vec::new(),
             },
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Card, Rank, Suit};
+    use crate::error::SettingsError;
+    use crate::game_settings::{BlackjackPayout, DoubleDownRule};
+    use crate::shoe::ShoeMode;
+    use crate::ledger::EntryKind;
+    use crate::hand::VisibleCard;
+    use crate::event::{GameEvent, Recipient};
+
+    fn card(rank: Rank) -> Card {
+        Card::new(rank, Suit::Spades)
+    }
+
+    /// A single seat betting 100 on one spot.
+    const ONE_BET: &[&[Money]] = &[&[Money::from_major(100)]];
+
+    /// Seats one player per entry of `bets` and places each seat's bets, one
+    /// per spot; a seat with no bets sits the round out. Then deals from a
+    /// stacked shoe holding exactly `cards`, written in short notation in the
+    /// order they are dealt.
+    fn scripted_game(mut settings: GameSettings, bets: &[&[Money]], cards: &str) -> Game {
+        settings.player_names = (1..=bets.len()).map(|seat| format!("Player{}", seat)).collect();
+        let shoe = Shoe::from_notation(cards).unwrap();
+        let mut game = Game::builder(settings).shoe(shoe).build().unwrap();
+        for (seat, spots) in bets.iter().enumerate() {
+            if !spots.is_empty() {
+                game.accept_user_bet(seat, spots).unwrap();
+            }
+        }
+        game.deal_initial_cards().unwrap();
+        game
+    }
+
+    /// Replaces the player at `seat` with one holding `bankroll`, keeping the
+    /// seat's name. Only used before any bet, so nothing leaves the ledger.
+    fn reseat_with_bankroll(game: &mut Game, seat: usize, bankroll: Money) {
+        let mut player = Player::with_bankroll(bankroll);
+        player.name = std::mem::take(&mut game.players[seat].name);
+        player.seat = seat;
+        game.players[seat] = player;
+    }
+
+    fn default_settings() -> GameSettings {
+        GameSettings::default_single_player("Player1".to_string())
+    }
+
+    fn play_out_dealer(game: &mut Game) {
+        while let GameState::DealerTurn { .. } = game.state {
+            game.next_dealer_turn().unwrap();
+        }
+    }
+
+    /// Declines insurance and stands on every hand, then plays out the dealer.
+    fn play_out_round(game: &mut Game) {
+        loop {
+            match game.state {
+                GameState::InsuranceOffered { active_seat, active_hand_index, .. } => {
+                    game.process_player_action(active_seat, GameAction::DeclineInsurance, active_hand_index).unwrap();
+                }
+                GameState::PlayerTurn { active_seat, active_hand_index, .. } => {
+                    game.process_player_action(active_seat, GameAction::Stand, active_hand_index).unwrap();
+                }
+                GameState::DealerTurn { .. } => game.next_dealer_turn().unwrap(),
+                _ => return,
+            }
+        }
+    }
+
+    #[test]
+    fn test_dealer_stands_on_soft_17_when_s17() {
+        // Player: T, 8 - Dealer: 6, A
+        let mut game = scripted_game(default_settings(), ONE_BET, "Ts 6h 8d Ac 4c");
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.privileged_dealer_hand().cards.len(), 2);
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+    }
+
+    #[test]
+    fn test_dealer_hits_soft_17_when_h17() {
+        let mut settings = default_settings();
+        settings.dealer_hits_soft_17 = true;
+        let mut game = scripted_game(settings, ONE_BET, "Ts 6h 8d Ac 4c");
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.privileged_dealer_hand().cards.len(), 3);
+        assert_eq!(game.privileged_dealer_hand().best_value(), 21);
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+    }
+
+    #[test]
+    fn test_dealer_stands_on_hard_17_when_h17() {
+        let mut settings = default_settings();
+        settings.dealer_hits_soft_17 = true;
+        let mut game = scripted_game(settings, ONE_BET, "Ts Th 8d 7c 4c");
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.privileged_dealer_hand().cards.len(), 2);
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+    }
+
+    #[test]
+    fn test_dealer_hits_multi_card_soft_17_when_h17() {
+        let mut settings = default_settings();
+        settings.dealer_hits_soft_17 = true;
+        let mut game = scripted_game(settings, ONE_BET, "Ts 2h 8d Ac 4c Td 5s");
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        // A-2-4 is soft 17, so the dealer draws a ten (hard 17) and stands
+        assert_eq!(game.privileged_dealer_hand().cards.len(), 4);
+        assert_eq!(game.privileged_dealer_hand().best_value(), 17);
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+    }
+
+    #[test]
+    fn test_natural_pays_three_to_two() {
+        // Player: A, K - Dealer: 9, 7
+        let game = scripted_game(default_settings(), ONE_BET, "As 9c Kd 7h");
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Blackjack));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_150));
+    }
+
+    #[test]
+    fn test_natural_pays_six_to_five() {
+        let mut settings = default_settings();
+        settings.blackjack_payout = BlackjackPayout::SixToFive;
+        let game = scripted_game(settings, ONE_BET, "As 9c Kd 7h");
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_120));
+    }
+
+    #[test]
+    fn test_six_to_five_payout_rounding() {
+        let play = |rounding: Rounding| {
+            let mut settings = default_settings();
+            settings.blackjack_payout = BlackjackPayout::SixToFive;
+            settings.payout_rounding = rounding;
+            let game = scripted_game(settings, &[&[Money::from_major(7)]], "As 9c Kd 7h");
+            game.players[0].bank_roll()
+        };
+
+        // 6:5 on $7 is $8.40, and the 40 cents are breakage with $1 chips
+        assert_eq!(play(Rounding::Down), Money::from_minor(1_000_840));
+        assert_eq!(play(Rounding::Breakage { chip: Money::from_major(1) }), Money::from_major(10_008));
+    }
+
+    #[test]
+    fn test_natural_pays_even_money() {
+        let mut settings = default_settings();
+        settings.blackjack_payout = BlackjackPayout::EvenMoney;
+        let game = scripted_game(settings, ONE_BET, "As 9c Kd 7h");
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_100));
+    }
+
+    #[test]
+    fn test_natural_pays_two_to_one() {
+        let mut settings = default_settings();
+        settings.blackjack_payout = BlackjackPayout::TwoToOne;
+        let game = scripted_game(settings, ONE_BET, "As 9c Kd 7h");
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_200));
+    }
+
+    #[test]
+    fn test_dealer_ace_offers_insurance() {
+        // Player: T, 9 - Dealer: A, 7
+        let game = scripted_game(default_settings(), ONE_BET, "Th As 9d 7c");
+        assert!(matches!(game.state, GameState::InsuranceOffered { .. }));
+
+        let dto = GameStateDto::from(game.state.clone());
+        assert_eq!(dto.phase, GamePhase::InsuranceOffered);
+        assert_eq!(dto.active_seat, Some(0));
+        assert_eq!(dto.seats[0].hands[0].insurance, None);
+    }
+
+    #[test]
+    fn test_insurance_pays_two_to_one_on_dealer_natural() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d Kc");
+        game.process_player_action(0, GameAction::Insurance(Money::from_major(50)), 0).unwrap();
+
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].hands[0].insurance, Some(Money::from_major(50)));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_000));
+
+        let dto = GameStateDto::from(game.state.clone());
+        assert_eq!(dto.seats[0].hands[0].insurance, Some(Money::from_major(50)));
+    }
+
+    #[test]
+    fn test_insurance_lost_when_dealer_has_no_natural() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d 7c");
+        game.process_player_action(0, GameAction::Insurance(Money::from_major(50)), 0).unwrap();
+
+        assert!(matches!(game.state, GameState::PlayerTurn { .. }));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_850));
+    }
+
+    #[test]
+    fn test_insurance_above_half_bet_is_rejected() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d 7c");
+        assert_eq!(game.process_player_action(0, GameAction::Insurance(Money::from_major(60)), 0), Err(EngineError::IllegalAction { action: GameAction::Insurance(Money::from_major(60)) }));
+
+        assert!(matches!(game.state, GameState::InsuranceOffered { .. }));
+        assert_eq!(game.players[0].hands[0].insurance, None);
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_decline_insurance_dealer_natural_loses() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d Qc");
+        game.process_player_action(0, GameAction::DeclineInsurance, 0).unwrap();
+
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_even_money_pays_one_to_one() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Ah As Kd Qc");
+        game.process_player_action(0, GameAction::EvenMoney, 0).unwrap();
+
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_100));
+    }
+
+    #[test]
+    fn test_even_money_requires_natural() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d 7c");
+        assert_eq!(game.process_player_action(0, GameAction::EvenMoney, 0), Err(EngineError::IllegalAction { action: GameAction::EvenMoney }));
+
+        assert!(matches!(game.state, GameState::InsuranceOffered { .. }));
+    }
+
+    #[test]
+    fn test_player_actions_rejected_while_insurance_offered() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d 7c");
+        assert_eq!(game.process_player_action(0, GameAction::Hit, 0), Err(EngineError::IllegalAction { action: GameAction::Hit }));
+
+        assert!(matches!(game.state, GameState::InsuranceOffered { .. }));
+        assert_eq!(game.players[0].hands[0].cards.len(), 2);
+    }
+
+    /// Settings for the given surrender rule. The surrender tests deal the
+    /// player 10-6 against the dealer's upcard and hole card.
+    fn surrender_settings(rule: SurrenderRule) -> GameSettings {
+        let mut settings = default_settings();
+        settings.surrender = rule;
+        settings
+    }
+
+    #[test]
+    fn test_surrender_refund_rounding() {
+        let play = |rounding: Rounding| {
+            let mut settings = surrender_settings(SurrenderRule::Late);
+            settings.bet_increment = Money::from_minor(25);
+            settings.payout_rounding = rounding;
+            let mut game = scripted_game(settings, &[&[Money::from_minor(525)]], "Th Ts 6d 7s");
+            game.process_player_action(0, GameAction::Surrender, 0).unwrap();
+            game.players[0].bank_roll()
+        };
+
+        // Half of $5.25 is $2.625
+        assert_eq!(play(Rounding::Down), Money::from_minor(999_737));
+        assert_eq!(play(Rounding::Breakage { chip: Money::from_minor(50) }), Money::from_minor(999_725));
+    }
+
+    #[test]
+    fn test_late_surrender_returns_half_bet() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Late), ONE_BET, "Th Ts 6d 7s");
+        game.process_player_action(0, GameAction::Surrender, 0).unwrap();
+
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Surrender));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_950));
+    }
+
+    #[test]
+    fn test_surrender_not_allowed_is_rejected() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th Ts 6d 7s");
+        assert_eq!(game.process_player_action(0, GameAction::Surrender, 0), Err(EngineError::IllegalAction { action: GameAction::Surrender }));
+
+        assert!(matches!(game.state, GameState::PlayerTurn { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, None);
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_surrender_after_hit_is_rejected() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Late), ONE_BET, "Th Ts 6d 7s 2c");
+        game.process_player_action(0, GameAction::Hit, 0).unwrap();
+        assert_eq!(game.process_player_action(0, GameAction::Surrender, 0), Err(EngineError::IllegalAction { action: GameAction::Surrender }));
+
+        assert_eq!(game.players[0].hands[0].outcome, None);
+        assert_eq!(game.players[0].hands[0].cards.len(), 3);
+    }
+
+    #[test]
+    fn test_late_surrender_unavailable_against_dealer_natural() {
+        let game = scripted_game(surrender_settings(SurrenderRule::Late), ONE_BET, "Th Ts 6d As");
+
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_early_surrender_against_dealer_natural() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Early), ONE_BET, "Th Ts 6d As");
+        assert!(matches!(game.state, GameState::SurrenderOffered { .. }));
+        assert_eq!(GameStateDto::from(game.state.clone()).phase, GamePhase::SurrenderOffered);
+        assert_eq!(game.legal_actions(0, 0), vec![GameAction::Surrender, GameAction::DeclineSurrender]);
+
+        game.process_player_action(0, GameAction::Surrender, 0).unwrap();
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Surrender));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_950));
+    }
+
+    #[test]
+    fn test_early_surrender_offer_rejects_other_actions() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Early), ONE_BET, "Th Ts 6d As");
+        assert_eq!(game.process_player_action(0, GameAction::Hit, 0), Err(EngineError::IllegalAction { action: GameAction::Hit }));
+
+        assert!(matches!(game.state, GameState::SurrenderOffered { .. }));
+        assert_eq!(game.players[0].hands[0].cards.len(), 2);
+    }
+
+    #[test]
+    fn test_declined_early_surrender_peeks_for_natural() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Early), ONE_BET, "Th Ts 6d As");
+        game.process_player_action(0, GameAction::DeclineSurrender, 0).unwrap();
+
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_declined_early_surrender_continues_to_player_turn() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Early), ONE_BET, "Th Ts 6d 7s");
+        game.process_player_action(0, GameAction::DeclineSurrender, 0).unwrap();
+
+        assert!(matches!(game.state, GameState::PlayerTurn { active_seat: 0, active_hand_index: 0, .. }));
+    }
+
+    #[test]
+    fn test_early_surrender_while_insurance_offered() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Early), ONE_BET, "Th As 6d Ks");
+        assert!(matches!(game.state, GameState::InsuranceOffered { .. }));
+
+        game.process_player_action(0, GameAction::Surrender, 0).unwrap();
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Surrender));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_950));
+    }
+
+    #[test]
+    fn test_natural_not_surrendered_while_insurance_offered() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Early), ONE_BET, "Ah As Kd 7s");
+
+        assert_eq!(
+            game.legal_actions(0, 0),
+            vec![GameAction::Insurance(Money::from_major(50)), GameAction::DeclineInsurance, GameAction::EvenMoney]
+        );
+        assert_eq!(game.process_player_action(0, GameAction::Surrender, 0), Err(EngineError::IllegalAction { action: GameAction::Surrender }));
+        assert!(matches!(game.state, GameState::InsuranceOffered { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, None);
+    }
+
+    #[test]
+    fn test_late_surrender_rejected_while_insurance_offered() {
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Late), ONE_BET, "Th As 6d Ks");
+        assert_eq!(game.process_player_action(0, GameAction::Surrender, 0), Err(EngineError::IllegalAction { action: GameAction::Surrender }));
+
+        assert!(matches!(game.state, GameState::InsuranceOffered { .. }));
+    }
+
+    #[test]
+    fn test_surrender_from_string() {
+        assert_eq!(GameAction::from_string("r"), Some(GameAction::Surrender));
+        assert_eq!(GameAction::from_string("Surrender"), Some(GameAction::Surrender));
+        assert_eq!(GameAction::from_string("no surrender"), Some(GameAction::DeclineSurrender));
+    }
+
+    /// Settings for the no-hole-card rule, under which the dealer is dealt
+    /// the upcard only: player, dealer upcard, player, then later draws.
+    fn no_hole_card_settings(original_bets_only: bool) -> GameSettings {
+        let mut settings = default_settings();
+        settings.hole_card = HoleCardRule::NoHoleCard { original_bets_only };
+        settings
+    }
+
+    #[test]
+    fn test_no_hole_card_deals_dealer_one_card() {
+        let game = scripted_game(no_hole_card_settings(false), ONE_BET, "Th Tc 9d");
+
+        assert!(matches!(game.state, GameState::PlayerTurn { .. }));
+        assert_eq!(game.privileged_dealer_hand().cards.len(), 1);
+        assert_eq!(game.players[0].hands[0].cards.len(), 2);
+    }
+
+    #[test]
+    fn test_no_hole_card_dealer_draws_second_card() {
+        let mut game = scripted_game(no_hole_card_settings(false), ONE_BET, "Th Tc 9d 7s");
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.privileged_dealer_hand().cards.len(), 2);
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_100));
+    }
+
+    #[test]
+    fn test_no_hole_card_double_lost_to_dealer_natural() {
+        let mut game = scripted_game(no_hole_card_settings(false), ONE_BET, "6h Ac 5d Ts Kh");
+        game.process_player_action(0, GameAction::DeclineInsurance, 0).unwrap();
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_800));
+    }
+
+    #[test]
+    fn test_no_hole_card_obo_refunds_double() {
+        let mut game = scripted_game(no_hole_card_settings(true), ONE_BET, "6h Ac 5d Ts Kh");
+        game.process_player_action(0, GameAction::DeclineInsurance, 0).unwrap();
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_no_hole_card_split_lost_to_dealer_natural() {
+        let mut game = scripted_game(no_hole_card_settings(false), ONE_BET, "8h Tc 8d Ts 9h Ac");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 1).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].hands[1].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_800));
+    }
+
+    #[test]
+    fn test_no_hole_card_obo_refunds_split() {
+        let mut game = scripted_game(no_hole_card_settings(true), ONE_BET, "8h Tc 8d Ts 9h Ac");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 1).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].hands[1].outcome, Some(HandOutcome::Push));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_no_hole_card_player_natural_waits_for_dealer() {
+        let mut game = scripted_game(no_hole_card_settings(false), ONE_BET, "Ah Tc Kd 6s 5h");
+        assert!(matches!(game.state, GameState::DealerTurn { .. }));
+
+        play_out_dealer(&mut game);
+        // Dealer stops after the second card since only a natural is in play
+        assert_eq!(game.privileged_dealer_hand().cards.len(), 2);
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Blackjack));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_150));
+    }
+
+    #[test]
+    fn test_no_hole_card_player_natural_pushes_dealer_natural() {
+        let mut game = scripted_game(no_hole_card_settings(false), ONE_BET, "Ah Tc Kd As");
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Push));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_000));
+    }
+
+    #[test]
+    fn test_no_hole_card_insurance_settled_on_second_card() {
+        let mut game = scripted_game(no_hole_card_settings(false), ONE_BET, "Th Ac 9d Ks");
+        game.process_player_action(0, GameAction::Insurance(Money::from_major(50)), 0).unwrap();
+        assert!(matches!(game.state, GameState::PlayerTurn { .. }));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_850));
+
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_000));
+    }
+
+    #[test]
+    fn test_no_hole_card_surrender_not_allowed() {
+        for rule in [SurrenderRule::Late, SurrenderRule::Early] {
+            let mut settings = no_hole_card_settings(false);
+            settings.surrender = rule;
+            let mut game = scripted_game(settings, ONE_BET, "Th Tc 6d");
+            assert_eq!(game.process_player_action(0, GameAction::Surrender, 0), Err(EngineError::IllegalAction { action: GameAction::Surrender }));
+
+            assert!(matches!(game.state, GameState::PlayerTurn { .. }));
+            assert_eq!(game.players[0].hands[0].outcome, None);
+        }
+    }
+
+    #[test]
+    fn test_no_hole_card_obo_keeps_busted_split_hand_lost() {
+        // Player: 8, 8 - Dealer: T, then the split hands draw T and 7, K
+        let mut game = scripted_game(no_hole_card_settings(true), ONE_BET, "8s Ts 8h Ts 7d Kd Ac");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        game.process_player_action(0, GameAction::Hit, 1).unwrap();
+        play_out_dealer(&mut game);
+
+        // Only a split bet still in play is refunded
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].hands[1].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_800));
+    }
+
+    #[test]
+    fn test_no_hole_card_obo_keeps_busted_double_lost() {
+        let mut game = scripted_game(no_hole_card_settings(true), ONE_BET, "Th Ts 2h Kd Ac");
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_800));
+    }
+
+    #[test]
+    fn test_split_hands_settled_when_last_hand_busts() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "8s Th 8d 7c Ts 4h Td");
+
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        game.process_player_action(0, GameAction::Hit, 1).unwrap();
+        play_out_dealer(&mut game);
+
+        // 8-10 still beats the dealer's 17 even though 8-4-10 busted
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+        assert_eq!(game.players[0].hands[1].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_000));
+    }
+
+    #[test]
+    fn test_hole_card_hidden_during_player_turn() {
+        let game = scripted_game(default_settings(), ONE_BET, "Th Ts 6d 7s");
+        let dto = GameStateDto::from(game.state.clone());
+
+        let dealer_hand = dto.dealer_hand.unwrap();
+        assert_eq!(dealer_hand.cards, vec![VisibleCard::FaceUp(card(Rank::Ten)), VisibleCard::FaceDown]);
+        assert_eq!(game.dealer_hand_view(), dealer_hand);
+        assert_eq!(game.privileged_dealer_hand().cards, vec![card(Rank::Ten), card(Rank::Seven)]);
+    }
+
+    #[test]
+    fn test_hole_card_hidden_while_insurance_offered() {
+        let game = scripted_game(default_settings(), ONE_BET, "Th As 9d Kc");
+        let dto = GameStateDto::from(game.state.clone());
+
+        assert_eq!(dto.dealer_hand.unwrap().cards[1], VisibleCard::FaceDown);
+    }
+
+    #[test]
+    fn test_hole_card_hidden_while_surrender_offered() {
+        let game = scripted_game(surrender_settings(SurrenderRule::Early), ONE_BET, "Th Ts 6d As");
+        let dto = GameStateDto::from(game.state.clone());
+
+        assert_eq!(dto.dealer_hand.unwrap().cards[1], VisibleCard::FaceDown);
+    }
+
+    #[test]
+    fn test_hole_card_revealed_on_dealer_turn() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th Ts 6d 7s");
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        let dto = GameStateDto::from(game.state.clone());
+
+        assert_eq!(dto.phase, GamePhase::DealerTurn);
+        assert_eq!(dto.dealer_hand.unwrap().cards, vec![
+            VisibleCard::FaceUp(card(Rank::Ten)),
+            VisibleCard::FaceUp(card(Rank::Seven)),
+        ]);
+    }
+
+    #[test]
+    fn test_double_after_split_disabled() {
+        let mut settings = default_settings();
+        settings.double_after_split = false;
+        // Player: 8, 8 - Dealer: T, 7
+        let mut game = scripted_game(settings, ONE_BET, "8s Th 8d 7c 3c");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        assert_eq!(game.process_player_action(0, GameAction::Double, 0), Err(EngineError::IllegalAction { action: GameAction::Double }));
+
+        assert_eq!(game.players[0].hands[0].cards.len(), 2);
+        assert!(!game.players[0].hands[0].doubled);
+    }
+
+    #[test]
+    fn test_double_after_split_enabled() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "8s Th 8d 7c 3c Td 2h");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+
+        assert!(game.players[0].hands[0].doubled);
+        assert_eq!(game.players[0].hands[0].cards.len(), 3);
+    }
+
+    #[test]
+    fn test_resplit_up_to_max_hands() {
+        let mut settings = default_settings();
+        settings.max_split_hands = 3;
+        let mut game = scripted_game(settings, ONE_BET, "8s Th 8d 7c 8c 8d");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        assert_eq!(game.players[0].hands.len(), 3);
+
+        // Hand 0 holds another pair of eights but the table limit is reached
+        assert_eq!(game.process_player_action(0, GameAction::Split, 0), Err(EngineError::IllegalAction { action: GameAction::Split }));
+        assert_eq!(game.players[0].hands.len(), 3);
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_700));
+    }
+
+    #[test]
+    fn test_split_aces_receive_one_card() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "As Th Ad 7c 5c 6d");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+
+        // Both aces get one card each and play passes straight to the dealer
+        assert!(matches!(game.state, GameState::DealerTurn { .. }));
+        assert_eq!(game.players[0].hands[0].cards.len(), 2);
+        assert_eq!(game.players[0].hands[1].cards.len(), 2);
+
+        assert_eq!(game.process_player_action(0, GameAction::Hit, 1), Err(EngineError::WrongPhase { phase: GamePhase::DealerTurn }));
+        assert_eq!(game.players[0].hands[1].cards.len(), 2);
+    }
+
+    #[test]
+    fn test_split_aces_may_hit_when_allowed() {
+        let mut settings = default_settings();
+        settings.split_aces_one_card = false;
+        let mut game = scripted_game(settings, ONE_BET, "As Th Ad 7c 5c 6d");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        assert!(matches!(game.state, GameState::PlayerTurn { active_hand_index: 0, .. }));
+
+        game.process_player_action(0, GameAction::Hit, 0).unwrap();
+        assert_eq!(game.players[0].hands[0].cards.len(), 3);
+    }
+
+    #[test]
+    fn test_resplit_aces() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "As Th Ad 7c Ac 6d");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        // Without resplitting aces, A-A on a split ace is just soft 12
+        assert_eq!(game.players[0].hands.len(), 2);
+        assert!(matches!(game.state, GameState::DealerTurn { .. }));
+
+        let mut settings = default_settings();
+        settings.resplit_aces = true;
+        let mut game = scripted_game(settings, ONE_BET, "As Th Ad 7c Ac 6d 7h 2s");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        assert!(matches!(game.state, GameState::PlayerTurn { active_hand_index: 0, .. }));
+
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        assert_eq!(game.players[0].hands.len(), 3);
+    }
+
+    #[test]
+    fn test_split_aces_21_is_not_a_natural_by_default() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "As Th Ad 7c Kc 6d");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+        // 200 staked, A-K wins 1:1, A-6 (17) pushes the dealer's 17
+        assert_eq!(game.players[0].hands[1].outcome, Some(HandOutcome::Push));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_100));
+    }
+
+    #[test]
+    fn test_split_aces_21_counts_as_natural() {
+        let mut settings = default_settings();
+        settings.split_aces_natural = true;
+        let mut game = scripted_game(settings, ONE_BET, "As Th Ad 7c Kc 6d");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Blackjack));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_150));
+    }
+
+    #[test]
+    fn test_split_unlike_tens_only_by_value() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Ks Th Qs 7c 2d");
+        assert_eq!(game.process_player_action(0, GameAction::Split, 0), Err(EngineError::IllegalAction { action: GameAction::Split }));
+        assert_eq!(game.players[0].hands.len(), 1);
+
+        let mut settings = default_settings();
+        settings.split_by_value = true;
+        let mut game = scripted_game(settings, ONE_BET, "Ks Th Qs 7c 2d");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        assert_eq!(game.players[0].hands.len(), 2);
+        assert_eq!(game.players[0].hands[1].cards[0].rank, Rank::Queen);
+    }
+
+    #[test]
+    fn test_double_on_three_cards_rejected() {
+        // Player: 3, 4 - Dealer: T, 7
+        let mut game = scripted_game(default_settings(), ONE_BET, "3s Th 4d 7c 2c");
+        game.process_player_action(0, GameAction::Hit, 0).unwrap();
+        assert_eq!(game.process_player_action(0, GameAction::Double, 0), Err(EngineError::IllegalAction { action: GameAction::Double }));
+
+        assert!(!game.players[0].hands[0].doubled);
+        assert_eq!(game.players[0].hands[0].cards.len(), 3);
+    }
+
+    #[test]
+    fn test_double_any_time() {
+        let mut settings = default_settings();
+        settings.double_any_time = true;
+        let mut game = scripted_game(settings, ONE_BET, "3s Th 4d 7c 2c 2h");
+        game.process_player_action(0, GameAction::Hit, 0).unwrap();
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+
+        assert!(game.players[0].hands[0].doubled);
+        assert_eq!(game.players[0].hands[0].cards.len(), 4);
+    }
+
+    #[test]
+    fn test_double_nine_to_eleven() {
+        let mut settings = default_settings();
+        settings.double_down = DoubleDownRule::NineToEleven;
+
+        let mut game = scripted_game(settings.clone(), ONE_BET, "4s Th 5d 7c Tc");
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        assert!(game.players[0].hands[0].doubled);
+
+        let mut game = scripted_game(settings, ONE_BET, "4s Th 4d 7c");
+        assert_eq!(game.process_player_action(0, GameAction::Double, 0), Err(EngineError::IllegalAction { action: GameAction::Double }));
+        assert!(!game.players[0].hands[0].doubled);
+    }
+
+    #[test]
+    fn test_double_ten_to_eleven() {
+        let mut settings = default_settings();
+        settings.double_down = DoubleDownRule::TenToEleven;
+
+        let mut game = scripted_game(settings.clone(), ONE_BET, "4s Th 5d 7c");
+        assert_eq!(game.process_player_action(0, GameAction::Double, 0), Err(EngineError::IllegalAction { action: GameAction::Double }));
+        assert!(!game.players[0].hands[0].doubled);
+
+        let mut game = scripted_game(settings, ONE_BET, "5s Th 6d 7c Tc");
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        assert!(game.players[0].hands[0].doubled);
+    }
+
+    #[test]
+    fn test_no_soft_doubling() {
+        let mut settings = default_settings();
+        settings.double_soft_hands = false;
+        let mut game = scripted_game(settings, ONE_BET, "As Th 7d 7c");
+        assert_eq!(game.process_player_action(0, GameAction::Double, 0), Err(EngineError::IllegalAction { action: GameAction::Double }));
+        assert!(!game.players[0].hands[0].doubled);
+
+        let mut game = scripted_game(default_settings(), ONE_BET, "As Th 7d 7c 3c");
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        assert!(game.players[0].hands[0].doubled);
+    }
+
+    #[test]
+    fn test_bet_validation() {
+        let mut settings = default_settings();
+        settings.table_minimum = Money::from_major(10);
+        settings.table_maximum = Money::from_major(500);
+        settings.bet_increment = Money::from_major(5);
+        let mut game = Game::new(settings).unwrap();
+
+        assert_eq!(game.accept_user_bet(0, &[Money::ZERO]), Err(EngineError::InvalidBet(BetError::NotPositive)));
+        assert_eq!(game.accept_user_bet(0, &[-Money::from_major(10)]), Err(EngineError::InvalidBet(BetError::NotPositive)));
+        assert_eq!(game.accept_user_bet(0, &[Money::from_major(5)]), Err(EngineError::InvalidBet(BetError::BelowMinimum { minimum: Money::from_major(10) })));
+        assert_eq!(game.accept_user_bet(0, &[Money::from_major(505)]), Err(EngineError::InvalidBet(BetError::AboveMaximum { maximum: Money::from_major(500) })));
+        assert_eq!(game.accept_user_bet(0, &[Money::from_major(12)]), Err(EngineError::InvalidBet(BetError::OffIncrement { increment: Money::from_major(5) })));
+        assert!(matches!(game.state, GameState::WaitingForBet { .. }));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_000));
+
+        assert_eq!(game.accept_user_bet(0, &[Money::from_major(15)]), Ok(()));
+        assert!(matches!(game.state, GameState::WaitingToDeal { .. }));
+        assert_eq!(game.state.seats()[0].hands[0].bet, Money::from_major(15));
+    }
+
+    #[test]
+    fn test_bet_above_bankroll_rejected() {
+        let mut game = Game::new(default_settings()).unwrap();
+        reseat_with_bankroll(&mut game, 0, Money::from_major(50));
+
+        assert_eq!(game.accept_user_bet(0, &[Money::from_major(100)]), Err(EngineError::InsufficientFunds { required: Money::from_major(100), available: Money::from_major(50) }));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(50));
+    }
+
+    #[test]
+    fn test_fractional_increment() {
+        let mut settings = default_settings();
+        settings.bet_increment = Money::from_minor(50);
+        let mut game = Game::new(settings).unwrap();
+
+        assert_eq!(
+            game.accept_user_bet(0, &[Money::from_minor(1_225)]),
+            Err(EngineError::InvalidBet(BetError::OffIncrement { increment: Money::from_minor(50) }))
+        );
+        assert_eq!(game.accept_user_bet(0, &[Money::from_minor(1_250)]), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_settings_rejected() {
+        let mut settings = default_settings();
+        settings.deck_count = 0;
+        assert_eq!(Game::new(settings).err(), Some(SettingsError::DeckCount.into()));
+    }
+
+    #[test]
+    fn test_operations_in_wrong_phase() {
+        let mut game = Game::new(default_settings()).unwrap();
+        let waiting_for_bet = Err(EngineError::WrongPhase { phase: GamePhase::WaitingForBet });
+        assert_eq!(game.deal_initial_cards(), waiting_for_bet);
+        assert_eq!(game.process_player_action(0, GameAction::Hit, 0), waiting_for_bet);
+        assert_eq!(game.next_dealer_turn(), waiting_for_bet);
+        assert_eq!(game.next_round(), waiting_for_bet);
+    }
+
+    #[test]
+    fn test_shuffle_burns_cards() {
+        let mut settings = default_settings();
+        settings.burn_cards = 3;
+        let mut game = Game::with_seed(settings, 9).unwrap();
+        assert!(!game.shoe_reshuffled());
+        game.shuffle_shoe().unwrap();
+        assert!(game.shoe_reshuffled());
+
+        assert_eq!(game.shoe.cards.len(), 309);
+        assert_eq!(game.shoe.burned_cards().len(), 3);
+
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        assert_eq!(game.shoe.dealt_cards().count(), 4);
+    }
+
+    #[test]
+    fn test_shuffle_rejected_mid_round() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "3s Th 4d 7c");
+        assert_eq!(game.shuffle_shoe(), Err(EngineError::WrongPhase { phase: GamePhase::PlayerTurn }));
+        assert_eq!(game.accept_user_bet(0, &[Money::from_major(100)]), Err(EngineError::WrongPhase { phase: GamePhase::PlayerTurn }));
+    }
+
+    #[test]
+    fn test_action_on_inactive_hand() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "8s Th 8d 7c 3c");
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        assert_eq!(
+            game.process_player_action(0, GameAction::Stand, 1),
+            Err(EngineError::InvalidHandIndex { index: 1 })
+        );
+        assert_eq!(
+            game.process_player_action(0, GameAction::Stand, 5),
+            Err(EngineError::InvalidHandIndex { index: 5 })
+        );
+    }
+
+    #[test]
+    fn test_double_without_funds() {
+        let shoe = Shoe::from_notation("5s Th 6d 7c").unwrap();
+        let mut game = Game::builder(default_settings()).shoe(shoe).build().unwrap();
+        reseat_with_bankroll(&mut game, 0, Money::from_major(150));
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        assert_eq!(
+            game.process_player_action(0, GameAction::Double, 0),
+            Err(EngineError::InsufficientFunds { required: Money::from_major(100), available: Money::from_major(50) })
+        );
+        assert!(!game.players[0].hands[0].doubled);
+    }
+
+    #[test]
+    fn test_hit_from_empty_shoe() {
+        // The script ends with the deal
+        let mut game = scripted_game(default_settings(), ONE_BET, "3s Th 4d 7c");
+        assert_eq!(game.process_player_action(0, GameAction::Hit, 0), Err(EngineError::EmptyShoe));
+    }
+
+    #[test]
+    fn test_legal_actions_on_starting_pair() {
+        let game = scripted_game(default_settings(), ONE_BET, "8s Th 8d 7c 3c");
+        let actions = vec![GameAction::Hit, GameAction::Stand, GameAction::Double, GameAction::Split];
+        assert_eq!(game.legal_actions(0, 0), actions);
+
+        let dto = GameStateDto::from(game.state.clone());
+        assert_eq!(dto.legal_actions, actions);
+        assert!(game.legal_actions(0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_legal_actions_after_hit() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "3s Th 4d 7c 2c");
+        game.process_player_action(0, GameAction::Hit, 0).unwrap();
+        assert_eq!(game.legal_actions(0, 0), vec![GameAction::Hit, GameAction::Stand]);
+    }
+
+    #[test]
+    fn test_legal_actions_respect_bankroll() {
+        let shoe = Shoe::from_notation("8s Th 8d 7c").unwrap();
+        let mut game = Game::builder(default_settings()).shoe(shoe).build().unwrap();
+        reseat_with_bankroll(&mut game, 0, Money::from_major(150));
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+
+        assert_eq!(game.legal_actions(0, 0), vec![GameAction::Hit, GameAction::Stand]);
+    }
+
+    #[test]
+    fn test_legal_actions_while_insurance_offered() {
+        let game = scripted_game(default_settings(), ONE_BET, "Ah As Kd 7c");
+        assert_eq!(
+            game.legal_actions(0, 0),
+            vec![GameAction::Insurance(Money::from_major(50)), GameAction::DeclineInsurance, GameAction::EvenMoney]
+        );
+
+        let game = scripted_game(default_settings(), ONE_BET, "Th As 9d 7c");
+        assert_eq!(game.legal_actions(0, 0), vec![GameAction::Insurance(Money::from_major(50)), GameAction::DeclineInsurance]);
+    }
+
+    #[test]
+    fn test_no_legal_actions_outside_player_turn() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th Ts 6d 7s");
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        assert!(game.legal_actions(0, 0).is_empty());
+        assert!(GameStateDto::from(game.state.clone()).legal_actions.is_empty());
+    }
+
+    #[test]
+    fn test_seats_are_dealt_in_order() {
+        let game = scripted_game(
+            default_settings(),
+            &[&[Money::from_major(10)], &[Money::from_major(20)], &[Money::from_major(30)]],
+            "2s 3s 4s Ts 5s 6s 7s 8s",
+        );
+
+        assert_eq!(game.players[0].hands[0].cards, vec![card(Rank::Two), card(Rank::Five)]);
+        assert_eq!(game.players[1].hands[0].cards, vec![card(Rank::Three), card(Rank::Six)]);
+        assert_eq!(game.players[2].hands[0].cards, vec![card(Rank::Four), card(Rank::Seven)]);
+        assert_eq!(game.privileged_dealer_hand().cards, vec![card(Rank::Ten), card(Rank::Eight)]);
+        assert_eq!(game.players[1].bank_roll(), Money::from_major(9_980));
+        assert!(matches!(game.state, GameState::PlayerTurn { active_seat: 0, active_hand_index: 0, .. }));
+    }
+
+    #[test]
+    fn test_play_moves_seat_by_seat() {
+        let mut game = scripted_game(
+            default_settings(),
+            &[&[Money::from_major(100)], &[Money::from_major(100)]],
+            "Ts Ts Ts 9s 6s 7s Ts",
+        );
+
+        assert_eq!(game.process_player_action(1, GameAction::Stand, 0), Err(EngineError::InvalidSeat { seat: 1 }));
+        assert!(game.legal_actions(1, 0).is_empty());
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        assert!(matches!(game.state, GameState::PlayerTurn { active_seat: 1, .. }));
+
+        // Seat 1 busts 16 with a ten; seat 0's 19 still beats the dealer's 17
+        game.process_player_action(1, GameAction::Hit, 0).unwrap();
+        assert!(matches!(game.state, GameState::DealerTurn { .. }));
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_100));
+        assert_eq!(game.players[1].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[1].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_seat_without_bet_sits_out() {
+        let mut game = scripted_game(
+            default_settings(),
+            &[&[Money::from_major(100)], &[], &[Money::from_major(100)]],
+            "Ts Ts 7s 9s 8s Ts",
+        );
+
+        assert!(game.players[1].hands.is_empty());
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        assert!(matches!(game.state, GameState::PlayerTurn { active_seat: 2, .. }));
+        game.process_player_action(2, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[1].bank_roll(), Money::from_major(10_000));
+        game.next_round().unwrap();
+        assert_eq!(game.players[1].hands.len(), 1);
+    }
+
+    #[test]
+    fn test_natural_at_one_seat_is_paid_and_skipped() {
+        let mut game = scripted_game(
+            default_settings(),
+            &[&[Money::from_major(100)], &[Money::from_major(100)]],
+            "As Ts 9s Ks 9s 8s",
+        );
+
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Blackjack));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_150));
+        assert!(matches!(game.state, GameState::PlayerTurn { active_seat: 1, .. }));
+
+        game.process_player_action(1, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+        assert_eq!(game.players[1].hands[0].outcome, Some(HandOutcome::Win));
+    }
+
+    #[test]
+    fn test_insurance_offered_to_each_seat() {
+        let mut game = scripted_game(
+            default_settings(),
+            &[&[Money::from_major(100)], &[Money::from_major(100)]],
+            "Ts 9s As 9s 9s Ks",
+        );
+
+        assert!(matches!(game.state, GameState::InsuranceOffered { active_seat: 0, .. }));
+        game.process_player_action(0, GameAction::Insurance(Money::from_major(50)), 0).unwrap();
+        assert!(matches!(game.state, GameState::InsuranceOffered { active_seat: 1, .. }));
+        game.process_player_action(1, GameAction::DeclineInsurance, 0).unwrap();
+
+        // The dealer peeks once every seat has answered
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_000));
+        assert_eq!(game.players[1].bank_roll(), Money::from_major(9_900));
+    }
+
+    #[test]
+    fn test_early_surrender_offered_to_each_seat() {
+        // Seat 0: T, 6 - Seat 1: 9, 6 - Dealer: T, A
+        let mut game = scripted_game(surrender_settings(SurrenderRule::Early), &[&[Money::from_major(100)], &[Money::from_major(100)]], "Th 9c Ts 6d 6h As");
+
+        assert!(matches!(game.state, GameState::SurrenderOffered { active_seat: 0, .. }));
+        game.process_player_action(0, GameAction::DeclineSurrender, 0).unwrap();
+        assert!(matches!(game.state, GameState::SurrenderOffered { active_seat: 1, .. }));
+        game.process_player_action(1, GameAction::Surrender, 0).unwrap();
+
+        // The dealer peeks once every seat has answered
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[1].hands[0].outcome, Some(HandOutcome::Surrender));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_900));
+        assert_eq!(game.players[1].bank_roll(), Money::from_major(9_950));
+
+        let actions: Vec<(usize, GameAction)> = game.history().last().unwrap().actions.iter()
+            .map(|action| (action.seat, action.action))
+            .collect();
+        assert_eq!(actions, vec![(0, GameAction::DeclineSurrender), (1, GameAction::Surrender)]);
+    }
+
+    #[test]
+    fn test_bets_by_seat() {
+        let mut settings = default_settings();
+        settings.player_names = vec!["Alice".to_string(), "Bob".to_string()];
+        let mut game = Game::new(settings).unwrap();
+
+        assert_eq!(game.accept_user_bet(2, &[Money::from_major(100)]), Err(EngineError::InvalidSeat { seat: 2 }));
+        game.accept_user_bet(1, &[Money::from_major(100)]).unwrap();
+        game.accept_user_bet(1, &[Money::from_major(50)]).unwrap();
+        assert_eq!(game.players[1].bank_roll(), Money::from_major(9_950));
+
+        reseat_with_bankroll(&mut game, 0, Money::from_major(40));
+        assert_eq!(
+            game.accept_user_bet(0, &[Money::from_major(50)]),
+            Err(EngineError::InsufficientFunds { required: Money::from_major(50), available: Money::from_major(40) })
+        );
+
+        let dto = GameStateDto::from(game.state.clone());
+        assert_eq!(dto.phase, GamePhase::WaitingToDeal);
+        assert_eq!(dto.seats.len(), 2);
+        assert_eq!(dto.seats[0].name, "Alice");
+        assert_eq!(dto.seats[1].name, "Bob");
+        assert_eq!(dto.seats[1].hands[0].bet, Money::from_major(50));
+    }
+
+    #[test]
+    fn test_spots_are_dealt_around_the_table() {
+        let game = scripted_game(
+            default_settings(),
+            &[&[Money::from_major(100), Money::from_major(50)], &[Money::from_major(25)]],
+            "2s 3s 4s Ts 5s 6s 7s 8s",
+        );
+
+        let alice = &game.players[0];
+        assert_eq!(alice.bank_roll(), Money::from_major(9_850));
+        assert_eq!(alice.hands[0].cards, vec![card(Rank::Two), card(Rank::Five)]);
+        assert_eq!((alice.hands[0].spot, alice.hands[0].bet), (0, Money::from_major(100)));
+        assert_eq!(alice.hands[1].cards, vec![card(Rank::Three), card(Rank::Six)]);
+        assert_eq!((alice.hands[1].spot, alice.hands[1].bet), (1, Money::from_major(50)));
+        assert_eq!(game.players[1].hands[0].cards, vec![card(Rank::Four), card(Rank::Seven)]);
+        assert_eq!(game.privileged_dealer_hand().cards, vec![card(Rank::Ten), card(Rank::Eight)]);
+    }
+
+    #[test]
+    fn test_split_hands_keep_their_spot() {
+        let bets: &[&[Money]] = &[&[Money::from_major(100), Money::from_major(100)]];
+        // Spot 0: 8, 8 - Spot 1: T, 9 - Dealer: T, 7
+        let mut game = scripted_game(default_settings(), bets, "8s Ts Th 8d 9s 7c 3h 2d");
+
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        let spots: Vec<usize> = game.players[0].hands.iter().map(|hand| hand.spot).collect();
+        assert_eq!(spots, vec![0, 0, 1]);
+
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 1).unwrap();
+        assert!(matches!(game.state, GameState::PlayerTurn { active_seat: 0, active_hand_index: 2, .. }));
+        assert_eq!(game.players[0].hands[2].cards, vec![card(Rank::Ten), card(Rank::Nine)]);
+        game.process_player_action(0, GameAction::Stand, 2).unwrap();
+        assert!(matches!(game.state, GameState::DealerTurn { .. }));
+    }
+
+    #[test]
+    fn test_split_limit_counts_hands_per_spot() {
+        let mut settings = default_settings();
+        settings.max_split_hands = 2;
+        let bets: &[&[Money]] = &[&[Money::from_major(100), Money::from_major(100)]];
+        let mut game = scripted_game(settings, bets, "9s 8s Th 9d 8d 7c 9h 8h");
+
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        assert_eq!(game.process_player_action(0, GameAction::Split, 0), Err(EngineError::IllegalAction { action: GameAction::Split }));
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 1).unwrap();
+        assert!(game.legal_actions(0, 2).contains(&GameAction::Split));
+    }
+
+    #[test]
+    fn test_spot_bets_validated() {
+        let mut game = Game::new(default_settings()).unwrap();
+        reseat_with_bankroll(&mut game, 0, Money::from_major(250));
+
+        assert_eq!(game.accept_user_bet(0, &[]), Err(EngineError::InvalidBet(BetError::SpotCount { maximum: 3 })));
+        assert_eq!(
+            game.accept_user_bet(0, &[Money::from_major(10), Money::from_major(10), Money::from_major(10), Money::from_major(10)]),
+            Err(EngineError::InvalidBet(BetError::SpotCount { maximum: 3 }))
+        );
+        assert_eq!(game.accept_user_bet(0, &[Money::from_major(100), Money::ZERO]), Err(EngineError::InvalidBet(BetError::NotPositive)));
+        assert_eq!(
+            game.accept_user_bet(0, &[Money::from_major(100), Money::from_major(100), Money::from_major(100)]),
+            Err(EngineError::InsufficientFunds { required: Money::from_major(300), available: Money::from_major(250) })
+        );
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(250));
+
+        game.accept_user_bet(0, &[Money::from_major(100), Money::from_major(100)]).unwrap();
+        game.accept_user_bet(0, &[Money::from_major(200)]).unwrap();
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(50));
+        assert_eq!(game.players[0].hands.len(), 1);
+    }
+
+    #[test]
+    fn test_insurance_offered_per_spot() {
+        let bets: &[&[Money]] = &[&[Money::from_major(100), Money::from_major(100)]];
+        // Spot 0: T, 9 - Spot 1: A, K - Dealer: A, 7
+        let mut game = scripted_game(default_settings(), bets, "Ts Ah Ac 9d Kd 7c");
+
+        assert!(matches!(game.state, GameState::InsuranceOffered { active_seat: 0, active_hand_index: 0, .. }));
+        game.process_player_action(0, GameAction::Insurance(Money::from_major(50)), 0).unwrap();
+        assert_eq!(game.legal_actions(0, 1), vec![
+            GameAction::Insurance(Money::from_major(50)), GameAction::DeclineInsurance, GameAction::EvenMoney
+        ]);
+        game.process_player_action(0, GameAction::EvenMoney, 1).unwrap();
+
+        assert!(matches!(game.state, GameState::PlayerTurn { active_hand_index: 0, .. }));
+        assert_eq!(game.players[0].hands[0].insurance, Some(Money::from_major(50)));
+        assert_eq!(game.players[0].hands[1].outcome, Some(HandOutcome::Win));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(9_950));
+    }
+
+    #[test]
+    fn test_seeded_games_deal_the_same_cards() {
+        let play_round = |seed: u64| {
+            let mut game = Game::with_seed(default_settings(), seed).unwrap();
+            game.shuffle_shoe().unwrap();
+            game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+            game.deal_initial_cards().unwrap();
+            (game.players[0].hands[0].cards.clone(), game.privileged_dealer_hand().clone())
+        };
+
+        assert_eq!(play_round(11), play_round(11));
+        assert_ne!(play_round(11), play_round(12));
+    }
+
+    #[test]
+    fn test_cut_card_reshuffles_between_rounds() {
+        let mut game = Game::with_seed(default_settings(), 3).unwrap();
+        game.shuffle_shoe().unwrap();
+        while !game.shoe.cut_card_reached() {
+            game.shoe.draw_card();
+        }
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        play_out_round(&mut game);
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        // The shuffle before the deal is reported for the whole round
+        assert!(game.shoe_reshuffled());
+
+        game.next_round().unwrap();
+        assert!(game.shoe_reshuffled());
+        // One card is burned after the reshuffle
+        assert_eq!(game.shoe.cards.len(), 311);
+        assert_eq!(game.shoe.burned_cards().len(), 1);
+        assert_eq!(game.shoe.dealt_cards().count(), 0);
+
+        // The flag lasts for the whole of the reshuffled round
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        assert!(game.shoe_reshuffled());
+        play_out_round(&mut game);
+
+        // And clears once the next round is dealt
+        game.next_round().unwrap();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        assert!(!game.shoe_reshuffled());
+    }
+
+    #[test]
+    fn test_shuffle_between_rounds_reported_for_next_round() {
+        let mut game = Game::with_seed(default_settings(), 8).unwrap();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        play_out_round(&mut game);
+        assert!(!game.shoe_reshuffled());
+
+        game.shuffle_shoe().unwrap();
+        game.next_round().unwrap();
+        assert!(game.shoe_reshuffled());
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        assert!(game.shoe_reshuffled());
+    }
+
+    #[test]
+    fn test_continuous_shoe_takes_cards_back_each_round() {
+        let mut settings = default_settings();
+        settings.shoe_mode = ShoeMode::Continuous { buffer: 10 };
+        let mut game = Game::with_seed(settings, 2).unwrap();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        play_out_round(&mut game);
+
+        game.next_round().unwrap();
+        assert_eq!(game.shoe.cards.len(), 312);
+        assert!(game.shoe.discarded.is_empty());
+        assert!(!game.shoe_reshuffled());
+    }
+
+    #[test]
+    fn test_short_shoe_reshuffled_at_deal() {
+        let mut game = Game::with_seed(default_settings(), 5).unwrap();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        while game.shoe.cards.len() > 5 {
+            game.shoe.draw_card();
+        }
+        game.deal_initial_cards().unwrap();
+
+        assert!(game.shoe_reshuffled());
+        assert_eq!(game.shoe.cards.len(), 312 - 1 - 4);
+    }
+
+    #[test]
+    fn test_scripted_split_round() {
+        let mut settings = default_settings();
+        settings.dealer_hits_soft_17 = true;
+        // Player: 8, 8 - Dealer: 6, A, then the split hands draw 3, T and 5, K
+        let shoe = Shoe::from_notation("8s 6h 8d Ac 3c Th 5d Kc Ks").unwrap();
+        let mut game = Game::builder(settings).shoe(shoe).build().unwrap();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        game.process_player_action(0, GameAction::Hit, 1).unwrap();
+        // Soft 17 hits to a hard 17
+        play_out_dealer(&mut game);
+
+        assert!(matches!(game.state, GameState::RoundComplete { .. }));
+        assert_eq!(game.privileged_dealer_hand().best_value(), 17);
+        assert_eq!(game.players[0].hands[0].outcome, Some(HandOutcome::Win));
+        assert_eq!(game.players[0].hands[1].outcome, Some(HandOutcome::Loss));
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_100));
+        assert!(game.shoe.cards.is_empty());
+    }
+
+    #[test]
+    fn test_ledger_records_every_bankroll_change() {
+        let mut settings = default_settings();
+        settings.dealer_hits_soft_17 = true;
+        // Same round as the scripted split round, after changing the bet once
+        let shoe = Shoe::from_notation("8s 6h 8d Ac 3c Th 5d Kc Ks").unwrap();
+        let mut game = Game::builder(settings).shoe(shoe).build().unwrap();
+        game.accept_user_bet(0, &[Money::from_major(50)]).unwrap();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        game.process_player_action(0, GameAction::Hit, 1).unwrap();
+        play_out_dealer(&mut game);
+
+        let player = &game.players[0];
+        let entries: Vec<(EntryKind, Money, usize)> = player.ledger().iter()
+            .map(|entry| (entry.kind, entry.amount, entry.hand_index))
+            .collect();
+        assert_eq!(entries, vec![
+            (EntryKind::Wager, -Money::from_major(50), 0),
+            (EntryKind::Refund, Money::from_major(50), 0),
+            (EntryKind::Wager, -Money::from_major(100), 0),
+            (EntryKind::Split, -Money::from_major(100), 1),
+            (EntryKind::Double, -Money::from_major(100), 0),
+            (EntryKind::Payout, Money::from_major(400), 0),
+        ]);
+        assert!(player.ledger().iter().all(|entry| entry.round == 1));
+        assert_eq!(player.round_net(1), player.bank_roll() - Money::from_major(10_000));
+        assert_eq!(player.ledger().last().unwrap().balance, player.bank_roll());
+
+        game.next_round().unwrap();
+        assert_eq!(game.round(), 2);
+    }
+
+    #[test]
+    fn test_ledger_records_insurance() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d Ts");
+        game.process_player_action(0, GameAction::Insurance(Money::from_major(50)), 0).unwrap();
+
+        let kinds: Vec<EntryKind> = game.players[0].ledger().iter().map(|entry| entry.kind).collect();
+        assert_eq!(kinds, vec![EntryKind::Wager, EntryKind::Insurance, EntryKind::Payout]);
+        assert_eq!(game.players[0].round_net(1), Money::ZERO);
+    }
+
+    #[test]
+    fn test_scripted_round_at_two_seats() {
+        let mut settings = default_settings();
+        settings.player_names = vec!["Ann".to_string(), "Bob".to_string()];
+        // Ann: A, K - Bob: 9, T - Dealer: 6, A, which stands on soft 17
+        let shoe = Shoe::from_notation("As 9h 6c Kd Th Ad").unwrap();
+        let mut game = Game::builder(settings).seed(7).shoe(shoe).build().unwrap();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.accept_user_bet(1, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+
+        assert!(matches!(game.state, GameState::PlayerTurn { active_seat: 1, .. }));
+        game.process_player_action(1, GameAction::Stand, 0).unwrap();
+        play_out_dealer(&mut game);
+
+        assert_eq!(game.privileged_dealer_hand().cards.len(), 2);
+        assert_eq!(game.players[0].bank_roll(), Money::from_major(10_150));
+        assert_eq!(game.players[1].bank_roll(), Money::from_major(10_100));
+    }
+
+    #[test]
+    fn test_history_records_completed_round() {
+        let mut settings = default_settings();
+        settings.dealer_hits_soft_17 = true;
+        let shoe = Shoe::from_notation("8s 6h 8d Ac 3c Th 5d Kc Ks").unwrap();
+        let mut game = Game::builder(settings).shoe(shoe).build().unwrap();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        game.process_player_action(0, GameAction::Hit, 1).unwrap();
+        assert!(game.history().is_empty());
+        play_out_dealer(&mut game);
+
+        let record = game.history().last().unwrap();
+        assert_eq!(record.round, 1);
+        assert_eq!(record.dealer_hand.best_value(), 17);
+        assert_eq!(record.seats[0].name, "Player1");
+        let outcomes: Vec<Option<HandOutcome>> = record.seats[0].hands.iter().map(|hand| hand.outcome.clone()).collect();
+        assert_eq!(outcomes, vec![Some(HandOutcome::Win), Some(HandOutcome::Loss)]);
+        assert_eq!(record.seats[0].net, Money::from_major(100));
+        let actions: Vec<(GameAction, usize)> = record.actions.iter().map(|action| (action.action, action.hand_index)).collect();
+        assert_eq!(actions, vec![(GameAction::Split, 0), (GameAction::Double, 0), (GameAction::Hit, 1)]);
+
+        // The record outlives the hands reset for the next round
+        game.next_round().unwrap();
+        assert_eq!(game.history().len(), 1);
+        assert_eq!(game.history().last().unwrap().seats[0].hands.len(), 2);
+        let previous: Vec<Money> = game.players[0].previous_hands(game.history()).map(|hand| hand.bet).collect();
+        assert_eq!(previous, vec![Money::from_major(200), Money::from_major(100)]);
+        assert!(game.players[0].hands[0].cards.is_empty());
+    }
+
+    #[test]
+    fn test_history_keeps_insurance_and_honours_limit() {
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d Ts");
+        game.process_player_action(0, GameAction::Insurance(Money::from_major(50)), 0).unwrap();
+        let record = game.history().last().unwrap();
+        assert_eq!(record.actions[0].action, GameAction::Insurance(Money::from_major(50)));
+        assert_eq!(record.net(), Money::ZERO);
+
+        let mut settings = default_settings();
+        settings.history_limit = 2;
+        let mut game = Game::builder(settings).shoe(Shoe::from_notation(&"Ts 9h Ac Kd ".repeat(3)).unwrap()).build().unwrap();
+        for _ in 0..3 {
+            game.accept_user_bet(0, &[Money::from_major(10)]).unwrap();
+            // The player's natural settles each round at the deal
+            game.deal_initial_cards().unwrap();
+            game.next_round().unwrap();
+        }
+        let rounds: Vec<u64> = game.history().iter().map(|record| record.round).collect();
+        assert_eq!(rounds, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_events_follow_scripted_round() {
+        let mut settings = default_settings();
+        settings.dealer_hits_soft_17 = true;
+        let shoe = Shoe::from_notation("8s 6h 8d Ac 3c Th 5d Kc Ks").unwrap();
+        let mut game = Game::builder(settings).shoe(shoe).build().unwrap();
+        let events = game.subscribe();
+        game.accept_user_bet(0, &[Money::from_major(100)]).unwrap();
+        game.deal_initial_cards().unwrap();
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Double, 0).unwrap();
+        game.process_player_action(0, GameAction::Hit, 1).unwrap();
+        play_out_dealer(&mut game);
+
+        let dealt = |notation: &str, seat: Option<(usize, usize)>, face_up: bool| GameEvent::CardDealt {
+            recipient: seat.map_or(Recipient::Dealer, |(seat, hand_index)| Recipient::Seat { seat, hand_index }),
+            card: Some(notation.parse().unwrap()).filter(|_| face_up),
+            face_up,
+        };
+        let action = |hand_index: usize, action: GameAction| GameEvent::ActionTaken { seat: 0, hand_index, action };
+        assert_eq!(events.try_iter().collect::<Vec<_>>(), vec![
+            GameEvent::BetPlaced { seat: 0, spot: 0, amount: Money::from_major(100) },
+            dealt("8s", Some((0, 0)), true),
+            dealt("6h", None, true),
+            dealt("8d", Some((0, 0)), true),
+            dealt("Ac", None, false),
+            action(0, GameAction::Split),
+            dealt("3c", Some((0, 0)), true),
+            action(0, GameAction::Double),
+            dealt("Th", Some((0, 0)), true),
+            dealt("5d", Some((0, 1)), true),
+            action(1, GameAction::Hit),
+            dealt("Kc", Some((0, 1)), true),
+            GameEvent::HandBusted { recipient: Recipient::Seat { seat: 0, hand_index: 1 } },
+            GameEvent::HandSettled { seat: 0, hand_index: 1, outcome: HandOutcome::Loss, returned: Money::ZERO },
+            GameEvent::HoleCardRevealed { card: "Ac".parse().unwrap() },
+            dealt("Ks", None, true),
+            GameEvent::HandSettled { seat: 0, hand_index: 0, outcome: HandOutcome::Win, returned: Money::from_major(400) },
+        ]);
+    }
+
+    #[test]
+    fn test_events_settle_each_hand_once() {
+        // The split hand busts before the dealer's second card makes a natural
+        let mut game = scripted_game(no_hole_card_settings(true), ONE_BET, "8s Ts 8h Ts 7d Kd Ac");
+        let events = game.subscribe();
+        game.process_player_action(0, GameAction::Split, 0).unwrap();
+        game.process_player_action(0, GameAction::Stand, 0).unwrap();
+        game.process_player_action(0, GameAction::Hit, 1).unwrap();
+        play_out_dealer(&mut game);
+
+        let settled: Vec<(usize, HandOutcome, Money)> = events.try_iter()
+            .filter_map(|event| match event {
+                GameEvent::HandSettled { hand_index, outcome, returned, .. } => Some((hand_index, outcome, returned)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(settled, vec![(1, HandOutcome::Loss, Money::ZERO), (0, HandOutcome::Loss, Money::ZERO)]);
+        let kinds: Vec<EntryKind> = game.players[0].ledger().iter().map(|entry| entry.kind).collect();
+        assert_eq!(kinds, vec![EntryKind::Wager, EntryKind::Split]);
+    }
+
+    #[test]
+    fn test_events_reach_every_observer() {
+        let counted = std::sync::Arc::new(std::sync::Mutex::new(0));
+        let mut game = scripted_game(default_settings(), ONE_BET, "Th As 9d Ts");
+        let counter = std::sync::Arc::clone(&counted);
+        game.add_observer(move |event: &GameEvent| {
+            if matches!(event, GameEvent::HoleCardRevealed { .. } | GameEvent::ShoeReshuffled) {
+                *counter.lock().unwrap() += 1;
+            }
+        });
+        let events = game.subscribe();
+
+        // The dealer's natural turns the hole card over at the peek
+        game.process_player_action(0, GameAction::DeclineInsurance, 0).unwrap();
+        game.next_round().unwrap();
+        game.shuffle_shoe().unwrap();
+        let received: Vec<GameEvent> = events.try_iter().collect();
+        assert_eq!(received, vec![
+            GameEvent::ActionTaken { seat: 0, hand_index: 0, action: GameAction::DeclineInsurance },
+            GameEvent::HandSettled { seat: 0, hand_index: 0, outcome: HandOutcome::Loss, returned: Money::ZERO },
+            GameEvent::HoleCardRevealed { card: card(Rank::Ten) },
+            GameEvent::ShoeReshuffled,
+        ]);
+        assert_eq!(*counted.lock().unwrap(), 2);
+    }
+}
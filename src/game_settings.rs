@@ -1,7 +1,136 @@
+use std::fmt;
+use crate::error::{EngineError, SettingsError};
+use crate::history::DEFAULT_HISTORY_LIMIT;
+use crate::money::{Money, Rounding};
+use crate::player::DEFAULT_BANKROLL;
+use crate::shoe::{ShoeMode, DEFAULT_PENETRATION};
+use crate::shuffle::ShuffleProcedure;
+
+/// The most players a table can seat.
+pub const MAX_SEATS: usize = 7;
+
+/// Represents the payout ratio for a natural blackjack.
+///
+/// * `ThreeToTwo` - The traditional 3:2 payout
+/// * `SixToFive` - The reduced 6:5 payout found on many low-limit tables
+/// * `EvenMoney` - Naturals pay 1:1 like any other win
+/// * `TwoToOne` - Promotional 2:1 payout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlackjackPayout {
+    ThreeToTwo,
+    SixToFive,
+    EvenMoney,
+    TwoToOne,
+}
+
+impl BlackjackPayout {
+    /// Returns the payout ratio as a `(numerator, denominator)` pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::game_settings::BlackjackPayout;
+    /// assert_eq!(BlackjackPayout::ThreeToTwo.ratio(), (3, 2));
+    /// assert_eq!(BlackjackPayout::SixToFive.ratio(), (6, 5));
+    /// ```
+    pub fn ratio(&self) -> (u32, u32) {
+        match self {
+            BlackjackPayout::ThreeToTwo => (3, 2),
+            BlackjackPayout::SixToFive => (6, 5),
+            BlackjackPayout::EvenMoney => (1, 1),
+            BlackjackPayout::TwoToOne => (2, 1),
+        }
+    }
+
+    /// Returns the winnings paid on top of the returned stake for a natural,
+    /// settling any remainder with `rounding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::game_settings::BlackjackPayout;
+    /// use blackjack_engine::money::{Money, Rounding};
+    /// let bet = Money::from_major(100);
+    /// assert_eq!(BlackjackPayout::ThreeToTwo.winnings(bet, Rounding::Down), Money::from_major(150));
+    /// assert_eq!(BlackjackPayout::SixToFive.winnings(bet, Rounding::Down), Money::from_major(120));
+    /// ```
+    pub fn winnings(&self, bet: Money, rounding: Rounding) -> Money {
+        let (numerator, denominator) = self.ratio();
+        bet.times_ratio(numerator as i64, denominator as i64, rounding)
+    }
+}
+
+impl fmt::Display for BlackjackPayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (numerator, denominator) = self.ratio();
+        write!(f, "{}:{}", numerator, denominator)
+    }
+}
+
+/// Determines whether and when a player may surrender their starting hand.
+///
+/// * `NotAllowed` - Surrender is not offered
+/// * `Late` - Surrender is allowed after the dealer has checked for a natural
+/// * `Early` - Surrender is also allowed before the dealer checks for a natural,
+///   against a ten or an Ace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SurrenderRule {
+    NotAllowed,
+    Late,
+    Early,
+}
+
+/// Determines how the dealer receives and checks the hole card.
+///
+/// * `Peek` - American rule: the dealer takes a hole card and peeks for a
+///   natural before the players act
+/// * `NoHoleCard` - European rule (ENHC): the dealer takes a second card only
+///   after the players finish. If that card makes a natural, doubles and
+///   splits are lost as well, unless `original_bets_only` (OBO) refunds
+///   everything beyond the original bet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoleCardRule {
+    Peek,
+    NoHoleCard { original_bets_only: bool },
+}
+
+/// Restricts which two-card totals a player may double down on.
+///
+/// * `AnyTwoCards` - Any first two cards may be doubled
+/// * `NineToEleven` - Only hands totalling 9, 10 or 11
+/// * `TenToEleven` - Only hands totalling 10 or 11
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoubleDownRule {
+    AnyTwoCards,
+    NineToEleven,
+    TenToEleven,
+}
+
+impl DoubleDownRule {
+    /// Returns true if a hand with the given best total may be doubled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::game_settings::DoubleDownRule;
+    /// assert!(DoubleDownRule::AnyTwoCards.allows_total(17));
+    /// assert!(DoubleDownRule::NineToEleven.allows_total(9));
+    /// assert!(!DoubleDownRule::TenToEleven.allows_total(9));
+    /// ```
+    pub fn allows_total(&self, total: u32) -> bool {
+        match self {
+            DoubleDownRule::AnyTwoCards => true,
+            DoubleDownRule::NineToEleven => (9..=11).contains(&total),
+            DoubleDownRule::TenToEleven => (10..=11).contains(&total),
+        }
+    }
+}
+
 /// Represents the configuration settings for a blackjack game.
 ///
 /// GameSettings holds all the configurable parameters that define how a blackjack
-/// game should be set up and run. This includes player information and deck configuration.
+/// game should be set up and run. This includes player information, deck configuration
+/// and the table rules the dealer plays by.
 /// The settings can be validated to ensure they meet game requirements.
 ///
 /// # Examples
@@ -18,10 +147,73 @@
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct GameSettings {
-    /// Name of the main player. Must be non-empty when validated.
-    pub player_name: String,
+    /// Names of the seated players, one per seat starting at first base.
+    /// A table seats 1 to [`MAX_SEATS`] players and every name must be
+    /// non-empty when validated.
+    pub player_names: Vec<String>,
     /// Number of decks to use in the shoe. Valid range is 1-8 decks.
     pub deck_count: u8,
+    /// Bankroll every seat starts the game with. Defaults to 10,000.
+    pub starting_bankroll: Money,
+    /// Whether the dealer hits a soft 17 (H17). When false the dealer stands
+    /// on all 17s (S17). Defaults to false.
+    pub dealer_hits_soft_17: bool,
+    /// Payout ratio for a natural blackjack. Defaults to 3:2.
+    pub blackjack_payout: BlackjackPayout,
+    /// Which surrender option the table offers. Defaults to no surrender.
+    pub surrender: SurrenderRule,
+    /// Whether the dealer takes and peeks at a hole card. Defaults to peeking.
+    pub hole_card: HoleCardRule,
+    /// Whether a hand may be doubled after a split (DAS). Defaults to true.
+    pub double_after_split: bool,
+    /// Maximum number of hands a player may hold through splitting and
+    /// resplitting. A value of 1 disables splitting. Defaults to 4.
+    pub max_split_hands: u8,
+    /// Whether split aces may be split again. Defaults to false.
+    pub resplit_aces: bool,
+    /// Whether each split ace receives only one card. Defaults to true.
+    pub split_aces_one_card: bool,
+    /// Whether an Ace and a ten-value card on a split ace is paid as a
+    /// natural rather than an ordinary 21. Defaults to false.
+    pub split_aces_natural: bool,
+    /// Whether any two cards of equal value may be split, such as K-Q,
+    /// rather than only cards of equal rank. Defaults to false.
+    pub split_by_value: bool,
+    /// Which totals may be doubled. Defaults to any two cards.
+    pub double_down: DoubleDownRule,
+    /// Whether soft hands such as A-7 may be doubled. Defaults to true.
+    pub double_soft_hands: bool,
+    /// Whether a hand of three or more cards may be doubled ("double any
+    /// time"). Defaults to false.
+    pub double_any_time: bool,
+    /// Smallest bet accepted at the table. Defaults to 5.
+    pub table_minimum: Money,
+    /// Largest bet accepted at the table. Defaults to 5,000.
+    pub table_maximum: Money,
+    /// Bets must be a whole multiple of this chip increment. Defaults to 1.
+    pub bet_increment: Money,
+    /// Most betting spots one player may play in a round. Must be at least
+    /// 1 when validated. Defaults to 3.
+    pub max_spots: u8,
+    /// Percentage of the shoe dealt before the cut card comes out and the
+    /// shoe is reshuffled between rounds. Valid range is 1-100. Defaults
+    /// to 75.
+    pub penetration: u8,
+    /// Number of cards burned face down after each shuffle. Defaults to 1.
+    pub burn_cards: u8,
+    /// Whether the shoe is dealt down to a cut card or runs through a
+    /// continuous shuffling machine. Defaults to a cut card.
+    pub shoe_mode: ShoeMode,
+    /// The steps the dealer follows to shuffle the shoe. Defaults to a
+    /// uniform shuffle.
+    pub shuffle_procedure: ShuffleProcedure,
+    /// How payouts that do not come out even, such as 3:2 on an odd bet or
+    /// half a bet returned on surrender, are settled. A breakage chip must
+    /// be positive. Defaults to rounding down to the cent.
+    pub payout_rounding: Rounding,
+    /// Number of completed rounds the game keeps in its history. Older
+    /// rounds are dropped, and 0 keeps none. Defaults to 100.
+    pub history_limit: usize,
 }
 
 impl GameSettings {
@@ -32,7 +224,7 @@ impl GameSettings {
     /// are valid.
     ///
     /// # Arguments
-    /// * `player_name` - Name of the main player
+    /// * `player_name` - Name of the player in the first seat
     /// * `deck_count` - Number of decks to use (should be between 1 and 8)
     ///
     /// # Returns
@@ -48,7 +240,7 @@ impl GameSettings {
     ///     "Alice".to_string(),
     ///     6,
     /// );
-    /// assert_eq!(settings.player_name, "Alice");
+    /// assert_eq!(settings.player_names, vec!["Alice"]);
     /// assert_eq!(settings.deck_count, 6);
     ///
     /// // Settings should be validated before use
@@ -56,8 +248,32 @@ impl GameSettings {
     /// ```
     pub fn new(player_name: String, deck_count: u8) -> Self {
         Self {
-            player_name,
+            player_names: vec![player_name],
             deck_count,
+            starting_bankroll: DEFAULT_BANKROLL,
+            dealer_hits_soft_17: false,
+            blackjack_payout: BlackjackPayout::ThreeToTwo,
+            surrender: SurrenderRule::NotAllowed,
+            hole_card: HoleCardRule::Peek,
+            double_after_split: true,
+            max_split_hands: 4,
+            resplit_aces: false,
+            split_aces_one_card: true,
+            split_aces_natural: false,
+            split_by_value: false,
+            double_down: DoubleDownRule::AnyTwoCards,
+            double_soft_hands: true,
+            double_any_time: false,
+            table_minimum: Money::from_major(5),
+            table_maximum: Money::from_major(5_000),
+            bet_increment: Money::from_major(1),
+            max_spots: 3,
+            penetration: DEFAULT_PENETRATION,
+            burn_cards: 1,
+            shoe_mode: ShoeMode::CutCard,
+            shuffle_procedure: ShuffleProcedure::uniform(),
+            payout_rounding: Rounding::Down,
+            history_limit: DEFAULT_HISTORY_LIMIT,
         }
     }
 
@@ -68,7 +284,7 @@ impl GameSettings {
     /// and provides a good balance between game flow and card counting difficulty.
     ///
     /// # Arguments
-    /// * `player_name` - Name of the main player
+    /// * `player_name` - Name of the player in the first seat
     ///
     /// # Returns
     /// A new GameSettings instance with default values
@@ -76,32 +292,37 @@ impl GameSettings {
     /// # Examples
     ///
     /// ```
-    /// use blackjack_engine::game_settings::GameSettings;
+    /// use blackjack_engine::game_settings::{BlackjackPayout, GameSettings};
     ///
     /// let settings = GameSettings::default_single_player("Bob".to_string());
     /// assert_eq!(settings.deck_count, 6); // Always uses 6 decks
-    /// assert_eq!(settings.player_name, "Bob");
+    /// assert_eq!(settings.player_names, vec!["Bob"]);
+    /// assert!(!settings.dealer_hits_soft_17); // Dealer stands on soft 17
+    /// assert_eq!(settings.blackjack_payout, BlackjackPayout::ThreeToTwo);
     /// ```
     pub fn default_single_player(player_name: String) -> Self {
-        Self {
-            player_name,
-            deck_count: 6,
-        }
+        GameSettings::new(player_name, 6)
     }
 
     /// Validates if the settings are within acceptable ranges.
     ///
     /// This method checks:
-    /// - Player name is not empty (after trimming whitespace)
+    /// - Between 1 and 7 players are seated
+    /// - No player name is empty (after trimming whitespace)
     /// - Deck count is between 1 and 8 (inclusive)
+    /// - Bet increment and table minimum are positive
+    /// - Table maximum is not below the table minimum
+    /// - Players may bet on at least one spot
     ///
     /// # Returns
     /// - `Ok(())` if all settings are valid
-    /// - `Err(String)` with a description of the first validation error encountered
+    /// - `Err(EngineError::InvalidSettings)` describing the first validation
+    ///   error encountered
     ///
     /// # Examples
     ///
     /// ```
+    /// use blackjack_engine::error::{EngineError, SettingsError};
     /// use blackjack_engine::game_settings::GameSettings;
     ///
     /// // Valid settings
@@ -112,22 +333,45 @@ impl GameSettings {
     /// let invalid = GameSettings::new("".to_string(), 6);
     /// assert_eq!(
     ///     invalid.validate().unwrap_err(),
-    ///     "Player name cannot be empty"
+    ///     EngineError::InvalidSettings(SettingsError::EmptyPlayerName)
     /// );
     ///
     /// // Invalid: too many decks
     /// let invalid = GameSettings::new("Alice".to_string(), 9);
     /// assert_eq!(
-    ///     invalid.validate().unwrap_err(),
+    ///     invalid.validate().unwrap_err().to_string(),
     ///     "Deck count must be between 1 and 8"
     /// );
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
-        if self.player_name.trim().is_empty() {
-            return Err("Player name cannot be empty".to_string());
+    pub fn validate(&self) -> Result<(), EngineError> {
+        if self.player_names.is_empty() || self.player_names.len() > MAX_SEATS {
+            return Err(SettingsError::SeatCount.into());
+        }
+        if self.player_names.iter().any(|name| name.trim().is_empty()) {
+            return Err(SettingsError::EmptyPlayerName.into());
         }
         if !(1..=8).contains(&self.deck_count) {
-            return Err("Deck count must be between 1 and 8".to_string());
+            return Err(SettingsError::DeckCount.into());
+        }
+        if !self.bet_increment.is_positive() {
+            return Err(SettingsError::BetIncrement.into());
+        }
+        if !self.table_minimum.is_positive() {
+            return Err(SettingsError::TableMinimum.into());
+        }
+        if self.table_maximum < self.table_minimum {
+            return Err(SettingsError::TableMaximum.into());
+        }
+        if self.max_spots == 0 {
+            return Err(SettingsError::MaxSpots.into());
+        }
+        if !(1..=100).contains(&self.penetration) {
+            return Err(SettingsError::Penetration.into());
+        }
+        if let Rounding::Breakage { chip } = self.payout_rounding {
+            if !chip.is_positive() {
+                return Err(SettingsError::BreakageChip.into());
+            }
         }
         Ok(())
     }
@@ -143,15 +387,119 @@ mod tests {
             "Player1".to_string(),
             6,
         );
-        assert_eq!(settings.player_name, "Player1");
+        assert_eq!(settings.player_names, vec!["Player1"]);
         assert_eq!(settings.deck_count, 6);
     }
 
     #[test]
     fn test_default_single_player() {
         let settings = GameSettings::default_single_player("Player1".to_string());
-        assert_eq!(settings.player_name, "Player1");
+        assert_eq!(settings.player_names, vec!["Player1"]);
         assert_eq!(settings.deck_count, 6);
+        assert!(!settings.dealer_hits_soft_17);
+        assert_eq!(settings.blackjack_payout, BlackjackPayout::ThreeToTwo);
+        assert_eq!(settings.surrender, SurrenderRule::NotAllowed);
+        assert_eq!(settings.hole_card, HoleCardRule::Peek);
+        assert!(settings.double_after_split);
+        assert_eq!(settings.max_split_hands, 4);
+        assert!(!settings.resplit_aces);
+        assert!(settings.split_aces_one_card);
+        assert!(!settings.split_aces_natural);
+        assert!(!settings.split_by_value);
+        assert_eq!(settings.double_down, DoubleDownRule::AnyTwoCards);
+        assert!(settings.double_soft_hands);
+        assert!(!settings.double_any_time);
+        assert_eq!(settings.table_minimum, Money::from_major(5));
+        assert_eq!(settings.table_maximum, Money::from_major(5_000));
+        assert_eq!(settings.bet_increment, Money::from_major(1));
+        assert_eq!(settings.max_spots, 3);
+        assert_eq!(settings.starting_bankroll, Money::from_major(10_000));
+        assert_eq!(settings.shuffle_procedure, ShuffleProcedure::uniform());
+    }
+
+    #[test]
+    fn test_validate_table_limits() {
+        let mut settings = GameSettings::new("Player1".to_string(), 6);
+        settings.table_minimum = Money::ZERO;
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::TableMinimum.into());
+
+        settings.table_minimum = Money::from_major(100);
+        settings.table_maximum = Money::from_major(50);
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::TableMaximum.into());
+
+        settings.table_maximum = Money::from_major(500);
+        settings.bet_increment = Money::ZERO;
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::BetIncrement.into());
+    }
+
+    #[test]
+    fn test_validate_penetration() {
+        let mut settings = GameSettings::new("Player1".to_string(), 6);
+        assert_eq!(settings.penetration, 75);
+
+        settings.penetration = 100;
+        assert!(settings.validate().is_ok());
+
+        settings.penetration = 0;
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::Penetration.into());
+
+        settings.penetration = 101;
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::Penetration.into());
+    }
+
+    #[test]
+    fn test_validate_breakage_chip() {
+        let mut settings = GameSettings::new("Player1".to_string(), 6);
+        settings.payout_rounding = Rounding::Breakage { chip: Money::from_minor(50) };
+        assert!(settings.validate().is_ok());
+
+        settings.payout_rounding = Rounding::Breakage { chip: Money::ZERO };
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::BreakageChip.into());
+    }
+
+    #[test]
+    fn test_validate_seats() {
+        let mut settings = GameSettings::new("Player1".to_string(), 6);
+        settings.player_names = (1..=7).map(|seat| format!("Player{}", seat)).collect();
+        assert!(settings.validate().is_ok());
+
+        settings.player_names.push("Player8".to_string());
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::SeatCount.into());
+
+        settings.player_names.clear();
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::SeatCount.into());
+
+        settings.player_names = vec!["Player1".to_string(), " ".to_string()];
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::EmptyPlayerName.into());
+
+        settings.player_names = vec!["Player1".to_string()];
+        settings.max_spots = 0;
+        assert_eq!(settings.validate().unwrap_err(), SettingsError::MaxSpots.into());
+    }
+
+    #[test]
+    fn test_double_down_rule_allows_total() {
+        for total in 4..=21 {
+            assert!(DoubleDownRule::AnyTwoCards.allows_total(total));
+            assert_eq!(DoubleDownRule::NineToEleven.allows_total(total), (9..=11).contains(&total));
+            assert_eq!(DoubleDownRule::TenToEleven.allows_total(total), (10..=11).contains(&total));
+        }
+    }
+
+    #[test]
+    fn test_blackjack_payout_winnings() {
+        assert_eq!(BlackjackPayout::ThreeToTwo.winnings(Money::from_major(10), Rounding::Down), Money::from_major(15));
+        assert_eq!(BlackjackPayout::SixToFive.winnings(Money::from_major(10), Rounding::Down), Money::from_major(12));
+        assert_eq!(BlackjackPayout::EvenMoney.winnings(Money::from_major(10), Rounding::Down), Money::from_major(10));
+        assert_eq!(BlackjackPayout::TwoToOne.winnings(Money::from_major(10), Rounding::Down), Money::from_major(20));
+    }
+
+    #[test]
+    fn test_blackjack_payout_to_string() {
+        assert_eq!(BlackjackPayout::ThreeToTwo.to_string(), "3:2");
+        assert_eq!(BlackjackPayout::SixToFive.to_string(), "6:5");
+        assert_eq!(BlackjackPayout::EvenMoney.to_string(), "1:1");
+        assert_eq!(BlackjackPayout::TwoToOne.to_string(), "2:1");
     }
 
     #[test]
@@ -171,7 +519,7 @@ mod tests {
         );
         assert!(settings.validate().is_err());
         assert_eq!(
-            settings.validate().unwrap_err(),
+            settings.validate().unwrap_err().to_string(),
             "Player name cannot be empty"
         );
     }
@@ -184,7 +532,7 @@ mod tests {
         );
         assert!(settings.validate().is_err());
         assert_eq!(
-            settings.validate().unwrap_err(),
+            settings.validate().unwrap_err().to_string(),
             "Deck count must be between 1 and 8"
         );
     }
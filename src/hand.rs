@@ -1,6 +1,8 @@
+use std::fmt;
 use serde::Serialize;
 use strum_macros::EnumIter;
 use crate::card::{Card, Rank};
+use crate::money::Money;
 
 /// Represents a player's hand in a blackjack game.
 ///
@@ -10,11 +12,20 @@ use crate::card::{Card, Rank};
 #[derive(PartialEq, Clone, Debug, Serialize)]
 pub struct Hand {
     /// Current bet amount for this hand
-    pub bet: f64,
+    pub bet: Money,
     /// Cards in the hand
     pub cards: Vec<Card>,
-    /// How the hand turned out (Win, Loss, Push, or Blackjack)
-    pub outcome: Option<HandOutcome>
+    /// How the hand turned out (Win, Loss, Push, Blackjack or Surrender)
+    pub outcome: Option<HandOutcome>,
+    /// Insurance side bet taken against a dealer Ace, if any
+    pub insurance: Option<Money>,
+    /// Whether the bet on this hand has been doubled
+    pub doubled: bool,
+    /// Whether this hand was created by splitting a pair
+    pub is_split: bool,
+    /// The betting spot this hand is played on. Hands split from it keep
+    /// the same spot.
+    pub spot: usize,
 }
 
 /// Represents the possible outcomes of a Blackjack hand.
@@ -23,16 +34,18 @@ pub struct Hand {
 /// * `Loss` - Player lost the hand
 /// * `Push` - Player tied with dealer
 /// * `Blackjack` - Player got a natural blackjack (Ace + 10-value card)
+/// * `Surrender` - Player gave up the hand and got half the bet back
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Serialize)]
 #[derive(EnumIter)]
 pub enum HandOutcome {
     Win,
     Loss,
     Push,
-    Blackjack
+    Blackjack,
+    Surrender,
 }
 
-impl HandOutcome {
+impl fmt::Display for HandOutcome {
     /// Converts the hand outcome to its string representation.
     ///
     /// # Examples
@@ -42,13 +55,15 @@ impl HandOutcome {
     /// assert_eq!(HandOutcome::Blackjack.to_string(), "BLACKJACK");
     /// assert_eq!(HandOutcome::Push.to_string(), "PUSH");
     /// ```
-    pub fn to_string(&self) -> String {
-        match self {
-            HandOutcome::Win => "WIN".to_string(),
-            HandOutcome::Loss => "LOSS".to_string(),
-            HandOutcome::Push => "PUSH".to_string(),
-            HandOutcome::Blackjack => "BLACKJACK".to_string(),
-        }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            HandOutcome::Win => "WIN",
+            HandOutcome::Loss => "LOSS",
+            HandOutcome::Push => "PUSH",
+            HandOutcome::Blackjack => "BLACKJACK",
+            HandOutcome::Surrender => "SURRENDER",
+        };
+        f.write_str(label)
     }
 }
 
@@ -58,15 +73,22 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
+    /// # use blackjack_engine::money::Money;
     /// let hand = Hand::new();
-    /// assert_eq!(hand.bet, 0f64);
+    /// assert_eq!(hand.bet, Money::ZERO);
     /// assert!(hand.cards.is_empty());
     /// ```
     pub fn new() -> Self {
         Self {
-            bet: 0f64,
+            bet: Money::ZERO,
             cards: Vec::new(),
-            outcome: None
+            outcome: None,
+            insurance: None,
+            doubled: false,
+            is_split: false,
+            spot: 0,
         }
     }
 
@@ -75,14 +97,21 @@ impl Hand {
     /// # Examples
     ///
     /// ```
-    /// let hand = Hand::with_bet(100.0);
-    /// assert_eq!(hand.bet, 100.0);
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
+    /// # use blackjack_engine::money::Money;
+    /// let hand = Hand::with_bet(Money::from_major(100));
+    /// assert_eq!(hand.bet, Money::from_major(100));
     /// ```
-    pub fn with_bet(bet: f64) -> Self {
+    pub fn with_bet(bet: Money) -> Self {
         Self {
             bet,
             cards: Vec::new(),
-            outcome: None
+            outcome: None,
+            insurance: None,
+            doubled: false,
+            is_split: false,
+            spot: 0,
         }
     }
 
@@ -91,15 +120,21 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let card = Card::new(Rank::Ace, Suit::Spades);
     /// let hand = Hand::with_card(card);
     /// assert_eq!(hand.cards.len(), 1);
     /// ```
     pub fn with_card(card: Card) -> Self {
         Self {
-            bet: 0f64,
+            bet: Money::ZERO,
             cards: vec![card],
-            outcome: None
+            outcome: None,
+            insurance: None,
+            doubled: false,
+            is_split: false,
+            spot: 0,
         }
     }
 
@@ -108,16 +143,23 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
+    /// # use blackjack_engine::money::Money;
     /// let card = Card::new(Rank::Ace, Suit::Spades);
-    /// let hand = Hand::with_card_and_bet(card, 100.0);
+    /// let hand = Hand::with_card_and_bet(card, Money::from_major(100));
     /// assert_eq!(hand.cards.len(), 1);
-    /// assert_eq!(hand.bet, 100.0);
+    /// assert_eq!(hand.bet, Money::from_major(100));
     /// ```
-    pub fn with_card_and_bet(card: Card, bet: f64) -> Self {
+    pub fn with_card_and_bet(card: Card, bet: Money) -> Self {
         Self {
             bet,
             cards: vec![card],
-            outcome: None
+            outcome: None,
+            insurance: None,
+            doubled: false,
+            is_split: false,
+            spot: 0,
         }
     }
 
@@ -126,6 +168,8 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let mut hand = Hand::new();
     /// hand.add_card(Card::new(Rank::Ace, Suit::Spades));
     /// assert_eq!(hand.cards.len(), 1);
@@ -137,18 +181,35 @@ impl Hand {
     /// Returns true if the hand can be split (has exactly 2 cards of the same rank).
     ///
     /// This is used to determine if the player can split their hand according to
-    /// standard Blackjack rules.
+    /// standard Blackjack rules. When `split_by_value` is set, any two cards of
+    /// the same value form a pair, so unlike ten-value cards such as K-Q can
+    /// be split too.
     ///
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let mut hand = Hand::new();
     /// hand.add_card(Card::new(Rank::Eight, Suit::Spades));
     /// hand.add_card(Card::new(Rank::Eight, Suit::Hearts));
-    /// assert!(hand.can_split());
+    /// assert!(hand.can_split(false));
+    ///
+    /// let mut tens = Hand::new();
+    /// tens.add_card(Card::new(Rank::King, Suit::Spades));
+    /// tens.add_card(Card::new(Rank::Queen, Suit::Hearts));
+    /// assert!(!tens.can_split(false));
+    /// assert!(tens.can_split(true));
     /// ```
-    pub fn can_split(&self) -> bool {
-        self.cards.len() == 2 && self.cards[0].rank == self.cards[1].rank
+    pub fn can_split(&self, split_by_value: bool) -> bool {
+        if self.cards.len() != 2 {
+            return false;
+        }
+        if split_by_value {
+            self.cards[0].rank.value() == self.cards[1].rank.value()
+        } else {
+            self.cards[0].rank == self.cards[1].rank
+        }
     }
 
     /// Doubles the current bet amount.
@@ -158,12 +219,17 @@ impl Hand {
     /// # Examples
     ///
     /// ```
-    /// let mut hand = Hand::with_bet(100.0);
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
+    /// # use blackjack_engine::money::Money;
+    /// let mut hand = Hand::with_bet(Money::from_major(100));
     /// hand.double_bet();
-    /// assert_eq!(hand.bet, 200.0);
+    /// assert_eq!(hand.bet, Money::from_major(200));
+    /// assert!(hand.doubled);
     /// ```
     pub fn double_bet(&mut self) {
-        self.bet = self.bet * 2f64;
+        self.bet = self.bet * 2;
+        self.doubled = true;
     }
 
     /// Calculates all possible hand values, accounting for aces.
@@ -174,6 +240,8 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let mut hand = Hand::new();
     /// hand.add_card(Card::new(Rank::Ace, Suit::Spades));
     /// hand.add_card(Card::new(Rank::Ace, Suit::Hearts));
@@ -213,6 +281,8 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let mut hand = Hand::new();
     /// hand.add_card(Card::new(Rank::Ace, Suit::Spades));
     /// hand.add_card(Card::new(Rank::King, Suit::Hearts));
@@ -227,6 +297,26 @@ impl Hand {
             .unwrap_or_else(|| values[0])
     }
 
+    /// Returns true if the hand is soft, i.e. it contains an ace that is
+    /// currently counted as 11 without busting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
+    /// let mut hand = Hand::new();
+    /// hand.add_card(Card::new(Rank::Ace, Suit::Spades));
+    /// hand.add_card(Card::new(Rank::Six, Suit::Hearts));
+    /// assert!(hand.is_soft());
+    /// hand.add_card(Card::new(Rank::King, Suit::Diamonds));
+    /// assert!(!hand.is_soft());
+    /// ```
+    pub fn is_soft(&self) -> bool {
+        let has_ace = self.cards.iter().any(|card| card.rank == Rank::Ace);
+        has_ace && self.possible_values()[0] + 10 <= 21
+    }
+
     /// Returns true if the hand is a natural blackjack (21 with exactly 2 cards).
     ///
     /// A natural blackjack typically pays out at higher odds than other wins.
@@ -234,6 +324,8 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let mut hand = Hand::new();
     /// hand.add_card(Card::new(Rank::Ace, Suit::Spades));
     /// hand.add_card(Card::new(Rank::King, Suit::Hearts));
@@ -243,6 +335,34 @@ impl Hand {
         self.cards.len() == 2 && self.best_value() == 21
     }
 
+    /// Returns true if the hand was created by splitting a pair of aces.
+    pub fn is_split_aces(&self) -> bool {
+        self.is_split && self.cards.first().is_some_and(|card| card.rank == Rank::Ace)
+    }
+
+    /// Returns true if the hand should be paid as a natural blackjack.
+    ///
+    /// Two-card 21s on split hands are ordinary 21s, unless
+    /// `split_aces_natural` is set and the hand came from splitting aces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
+    /// let mut hand = Hand::new();
+    /// hand.add_card(Card::new(Rank::Ace, Suit::Spades));
+    /// hand.add_card(Card::new(Rank::King, Suit::Hearts));
+    /// assert!(hand.pays_as_natural(false));
+    /// hand.is_split = true;
+    /// assert!(!hand.pays_as_natural(false));
+    /// assert!(hand.pays_as_natural(true));
+    /// ```
+    pub fn pays_as_natural(&self, split_aces_natural: bool) -> bool {
+        self.is_natural_blackjack()
+            && (!self.is_split || (split_aces_natural && self.is_split_aces()))
+    }
+
     /// Returns true if the hand totals 21 (not necessarily a natural blackjack).
     ///
     /// This differs from `is_natural_blackjack()` in that it returns true for any
@@ -251,6 +371,8 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let mut hand = Hand::new();
     /// hand.add_card(Card::new(Rank::Five, Suit::Spades));
     /// hand.add_card(Card::new(Rank::Six, Suit::Hearts));
@@ -267,6 +389,8 @@ impl Hand {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let mut hand = Hand::new();
     /// hand.add_card(Card::new(Rank::King, Suit::Spades));
     /// hand.add_card(Card::new(Rank::Queen, Suit::Hearts));
@@ -276,24 +400,90 @@ impl Hand {
     pub fn is_busted(&self) -> bool {
         self.possible_values().iter().all(|&v| v > 21)
     }
+}
+
+impl Default for Hand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
+impl fmt::Display for Hand {
     /// Returns a string representation of the hand, showing all cards.
     ///
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::Hand;
     /// let mut hand = Hand::new();
     /// hand.add_card(Card::new(Rank::Ace, Suit::Spades));
     /// hand.add_card(Card::new(Rank::King, Suit::Hearts));
     /// assert_eq!(hand.to_string(), "A♠️ K❤️ ");
     /// ```
-    pub fn to_string(&self) -> String {
-        let mut hand_as_string = String::from("");
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for card in self.cards.iter() {
-            hand_as_string.push_str(&*card.to_string());
-            hand_as_string.push(' ');
+            write!(f, "{} ", card)?;
+        }
+        Ok(())
+    }
+}
+
+/// A card as seen by the players: either face up or a face-down marker.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub enum VisibleCard {
+    FaceUp(Card),
+    FaceDown,
+}
+
+/// A view of the dealer's hand that is safe to show to players.
+///
+/// Before the dealer's turn only the upcard is visible and the hole card is
+/// replaced by [`VisibleCard::FaceDown`]. Once the dealer plays, every card
+/// is face up.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct DealerHandView {
+    /// The dealer's cards in the order they were dealt
+    pub cards: Vec<VisibleCard>,
+}
+
+impl DealerHandView {
+    /// Creates a view that shows only the dealer's upcard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::hand::{DealerHandView, Hand, VisibleCard};
+    /// let mut hand = Hand::new();
+    /// hand.add_card(Card::new(Rank::Ace, Suit::Spades));
+    /// hand.add_card(Card::new(Rank::King, Suit::Hearts));
+    /// let view = DealerHandView::hidden(&hand);
+    /// assert_eq!(view.cards[1], VisibleCard::FaceDown);
+    /// ```
+    pub fn hidden(hand: &Hand) -> Self {
+        let cards = hand.cards.iter().enumerate().map(|(i, card)| {
+            if i == 0 {
+                VisibleCard::FaceUp(card.clone())
+            } else {
+                VisibleCard::FaceDown
+            }
+        }).collect();
+        Self { cards }
+    }
+
+    /// Creates a view with every card face up.
+    pub fn revealed(hand: &Hand) -> Self {
+        let cards = hand.cards.iter().cloned().map(VisibleCard::FaceUp).collect();
+        Self { cards }
+    }
+
+    /// Returns the dealer's upcard, if one has been dealt.
+    pub fn upcard(&self) -> Option<&Card> {
+        match self.cards.first() {
+            Some(VisibleCard::FaceUp(card)) => Some(card),
+            _ => None,
         }
-        hand_as_string
     }
 }
 
@@ -305,14 +495,14 @@ mod tests {
     #[test]
     fn test_new_hand() {
         let hand = Hand::new();
-        assert_eq!(hand.bet, 0f64);
+        assert_eq!(hand.bet, Money::ZERO);
         assert!(hand.cards.is_empty());
     }
 
     #[test]
     fn test_with_bet() {
-        let hand = Hand::with_bet(200f64);
-        assert_eq!(hand.bet, 200f64);
+        let hand = Hand::with_bet(Money::from_major(200));
+        assert_eq!(hand.bet, Money::from_major(200));
     }
 
     #[test]
@@ -323,6 +513,23 @@ mod tests {
         assert_eq!(hand.cards.len(), 1);
     }
 
+    #[test]
+    fn test_can_split() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Jack, Suit::Spades));
+        hand.add_card(Card::new(Rank::Ten, Suit::Hearts));
+        assert!(!hand.can_split(false));
+        assert!(hand.can_split(true));
+
+        hand.add_card(Card::new(Rank::Two, Suit::Hearts));
+        assert!(!hand.can_split(true));
+
+        let mut unpaired = Hand::new();
+        unpaired.add_card(Card::new(Rank::Nine, Suit::Spades));
+        unpaired.add_card(Card::new(Rank::Ten, Suit::Hearts));
+        assert!(!unpaired.can_split(true));
+    }
+
     #[test]
     fn test_possible_values() {
         let mut hand = Hand::new();
@@ -340,6 +547,22 @@ mod tests {
         assert_eq!(hand.best_value(), 21);
     }
 
+    #[test]
+    fn test_is_soft() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Ace, Suit::Spades));
+        hand.add_card(Card::new(Rank::Six, Suit::Hearts));
+        assert!(hand.is_soft());
+
+        hand.add_card(Card::new(Rank::Ten, Suit::Hearts));
+        assert!(!hand.is_soft());
+
+        let mut hard = Hand::new();
+        hard.add_card(Card::new(Rank::Ten, Suit::Spades));
+        hard.add_card(Card::new(Rank::Seven, Suit::Hearts));
+        assert!(!hard.is_soft());
+    }
+
     #[test]
     fn test_blackjack() {
         let mut hand = Hand::new();
@@ -356,4 +579,39 @@ mod tests {
         hand.add_card(Card::new(Rank::Jack, Suit::Diamonds));
         assert!(hand.is_busted());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_split_aces_natural() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Ace, Suit::Spades));
+        hand.add_card(Card::new(Rank::Queen, Suit::Hearts));
+        hand.is_split = true;
+        assert!(hand.is_split_aces());
+        assert!(!hand.pays_as_natural(false));
+        assert!(hand.pays_as_natural(true));
+
+        let mut tens = Hand::new();
+        tens.add_card(Card::new(Rank::King, Suit::Spades));
+        tens.add_card(Card::new(Rank::Ace, Suit::Hearts));
+        tens.is_split = true;
+        assert!(!tens.is_split_aces());
+        assert!(!tens.pays_as_natural(true));
+    }
+
+    #[test]
+    fn test_dealer_view_hides_hole_card() {
+        let mut hand = Hand::new();
+        hand.add_card(Card::new(Rank::Ace, Suit::Spades));
+        hand.add_card(Card::new(Rank::King, Suit::Hearts));
+
+        let view = DealerHandView::hidden(&hand);
+        assert_eq!(view.upcard(), Some(&Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!(view.cards, vec![
+            VisibleCard::FaceUp(Card::new(Rank::Ace, Suit::Spades)),
+            VisibleCard::FaceDown,
+        ]);
+
+        let revealed = DealerHandView::revealed(&hand);
+        assert_eq!(revealed.cards[1], VisibleCard::FaceUp(Card::new(Rank::King, Suit::Hearts)));
+    }
+}
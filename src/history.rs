@@ -0,0 +1,274 @@
+use std::collections::VecDeque;
+use std::fmt;
+use serde::Serialize;
+use crate::game::GameAction;
+use crate::hand::Hand;
+use crate::money::Money;
+
+/// Number of completed rounds a game keeps by default.
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// An action a player took during a round, in the order it was taken.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ActionRecord {
+    /// The seat that took the action
+    pub seat: usize,
+    /// The index of the hand it was taken on at the time. Splits insert
+    /// hands, so later hands on the seat may have moved up since.
+    pub hand_index: usize,
+    /// The action taken
+    pub action: GameAction,
+}
+
+impl fmt::Display for ActionRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Seat {} hand {}: {}", self.seat, self.hand_index, self.action)?;
+        if let GameAction::Insurance(amount) = self.action {
+            write!(f, " {}", amount)?;
+        }
+        Ok(())
+    }
+}
+
+/// How one seat finished a completed round.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SeatRecord {
+    /// Name of the seated player
+    pub name: String,
+    /// The seat's settled hands, each with its bet and outcome. Empty if
+    /// the seat sat the round out.
+    pub hands: Vec<Hand>,
+    /// The net change to the player's bankroll over the round
+    pub net: Money,
+}
+
+/// Everything that happened in a completed round.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RoundRecord {
+    /// The round number, counting from 1
+    pub round: u64,
+    /// The dealer's final hand
+    pub dealer_hand: Hand,
+    /// Every seat at the table, in seat order
+    pub seats: Vec<SeatRecord>,
+    /// The players' actions, in the order they were taken
+    pub actions: Vec<ActionRecord>,
+}
+
+impl RoundRecord {
+    /// Returns the net change to every bankroll at the table over the round.
+    pub fn net(&self) -> Money {
+        self.seats.iter().map(|seat| seat.net).sum()
+    }
+}
+
+impl fmt::Display for RoundRecord {
+    /// Formats the round as a short multi-line summary: the dealer's hand,
+    /// then each hand played with its bet and outcome, then the actions.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Round {}: dealer {}({})", self.round, self.dealer_hand, self.dealer_hand.best_value())?;
+        for (seat, record) in self.seats.iter().enumerate() {
+            for hand in record.hands.iter() {
+                let outcome = hand.outcome.as_ref().map_or("-".to_string(), |outcome| outcome.to_string());
+                writeln!(f, "  Seat {} {} spot {}: {}({}) bet {} {}", seat, record.name, hand.spot, hand, hand.best_value(), hand.bet, outcome)?;
+            }
+            writeln!(f, "  Seat {} {} net {}", seat, record.name, record.net)?;
+        }
+        for action in self.actions.iter() {
+            writeln!(f, "  {}", action)?;
+        }
+        Ok(())
+    }
+}
+
+/// The most recent completed rounds of a game, oldest first.
+///
+/// Once `limit` rounds are held, recording another round drops the oldest.
+/// A limit of 0 keeps no history. Records serialize with serde, or can be
+/// exported as CSV with [`RoundHistory::to_csv`].
+///
+/// # Examples
+///
+/// ```
+/// use blackjack_engine::hand::Hand;
+/// use blackjack_engine::history::{RoundHistory, RoundRecord};
+/// let mut history = RoundHistory::new(2);
+/// for round in 1..=3 {
+///     history.push(RoundRecord { round, dealer_hand: Hand::new(), seats: Vec::new(), actions: Vec::new() });
+/// }
+/// assert_eq!(history.len(), 2);
+/// assert_eq!(history.iter().next().unwrap().round, 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RoundHistory {
+    records: VecDeque<RoundRecord>,
+    limit: usize,
+}
+
+impl RoundHistory {
+    /// Creates an empty history that holds at most `limit` rounds.
+    pub fn new(limit: usize) -> RoundHistory {
+        RoundHistory { records: VecDeque::new(), limit }
+    }
+
+    /// Returns the most rounds the history holds.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Adds a completed round, dropping the oldest rounds beyond the limit.
+    pub fn push(&mut self, record: RoundRecord) {
+        self.records.push_back(record);
+        while self.records.len() > self.limit {
+            self.records.pop_front();
+        }
+    }
+
+    /// Returns the number of rounds held.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if no rounds are held.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the held rounds, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &RoundRecord> {
+        self.records.iter()
+    }
+
+    /// Returns the most recently completed round.
+    pub fn last(&self) -> Option<&RoundRecord> {
+        self.records.back()
+    }
+
+    /// Returns the record of round number `round`, if it is still held.
+    pub fn round(&self, round: u64) -> Option<&RoundRecord> {
+        self.records.iter().find(|record| record.round == round)
+    }
+
+    /// Returns the settled hands `seat` played in the held rounds, oldest
+    /// first. Rounds the seat sat out add no hands.
+    pub fn previous_hands(&self, seat: usize) -> impl Iterator<Item = &Hand> {
+        self.records.iter()
+            .filter_map(move |record| record.seats.get(seat))
+            .flat_map(|record| record.hands.iter())
+    }
+
+    /// Returns a copy of every held round, oldest first, for exporting
+    /// through serde.
+    pub fn export(&self) -> Vec<RoundRecord> {
+        self.records.iter().cloned().collect()
+    }
+
+    /// Exports the held rounds as CSV, one row per hand played, under a
+    /// header row. Cards are written in short notation such as `As Td`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::history::RoundHistory;
+    /// let history = RoundHistory::new(10);
+    /// assert_eq!(
+    ///     history.to_csv(),
+    ///     "round,seat,name,spot,cards,total,bet,outcome,dealer_cards,dealer_total,seat_net\n"
+    /// );
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("round,seat,name,spot,cards,total,bet,outcome,dealer_cards,dealer_total,seat_net\n");
+        for record in self.records.iter() {
+            let dealer_cards = notation(&record.dealer_hand);
+            let dealer_total = record.dealer_hand.best_value();
+            for (seat, seat_record) in record.seats.iter().enumerate() {
+                for hand in seat_record.hands.iter() {
+                    let outcome = hand.outcome.as_ref().map_or(String::new(), |outcome| outcome.to_string());
+                    csv.push_str(&format!(
+                        "{},{},{},{},{},{},{},{},{},{},{}\n",
+                        record.round,
+                        seat,
+                        csv_field(&seat_record.name),
+                        hand.spot,
+                        notation(hand),
+                        hand.best_value(),
+                        hand.bet,
+                        outcome,
+                        dealer_cards,
+                        dealer_total,
+                        seat_record.net,
+                    ));
+                }
+            }
+        }
+        csv
+    }
+}
+
+/// Writes a hand's cards in short notation, separated by spaces.
+fn notation(hand: &Hand) -> String {
+    hand.cards.iter().map(|card| card.notation()).collect::<Vec<_>>().join(" ")
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Card, Rank, Suit};
+    use crate::hand::HandOutcome;
+
+    fn record(round: u64) -> RoundRecord {
+        let mut dealer_hand = Hand::new();
+        dealer_hand.add_card(Card::new(Rank::Ten, Suit::Clubs));
+        dealer_hand.add_card(Card::new(Rank::Seven, Suit::Hearts));
+        let mut hand = Hand::with_bet(Money::from_major(10));
+        hand.add_card(Card::new(Rank::Ace, Suit::Spades));
+        hand.add_card(Card::new(Rank::Nine, Suit::Diamonds));
+        hand.outcome = Some(HandOutcome::Win);
+        RoundRecord {
+            round,
+            dealer_hand,
+            seats: vec![SeatRecord { name: "Smith, J".to_string(), hands: vec![hand], net: Money::from_major(10) }],
+            actions: vec![ActionRecord { seat: 0, hand_index: 0, action: GameAction::Stand }],
+        }
+    }
+
+    #[test]
+    fn test_history_drops_oldest_rounds() {
+        let mut history = RoundHistory::new(3);
+        for round in 1..=5 {
+            history.push(record(round));
+        }
+        let rounds: Vec<u64> = history.iter().map(|record| record.round).collect();
+        assert_eq!(rounds, vec![3, 4, 5]);
+        assert_eq!(history.last().unwrap().round, 5);
+        assert!(history.round(2).is_none());
+        assert_eq!(history.round(4).unwrap().net(), Money::from_major(10));
+
+        assert_eq!(history.previous_hands(0).count(), 3);
+        assert_eq!(history.previous_hands(1).count(), 0);
+
+        let mut disabled = RoundHistory::new(0);
+        disabled.push(record(1));
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let mut history = RoundHistory::new(10);
+        history.push(record(7));
+        let csv = history.to_csv();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1], "7,0,\"Smith, J\",0,As 9d,20,10.00,WIN,Tc 7h,17,10.00");
+        assert_eq!(history.export(), vec![record(7)]);
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use serde::Serialize;
+use crate::hand::HandOutcome;
+use crate::money::Money;
+
+/// The reason for a change to a player's bankroll.
+///
+/// * `Wager` - The original bet placed on a spot
+/// * `Refund` - Money handed back outside the normal settlement: a bet
+///   replaced before the deal, or the doubled part of a lost bet returned
+///   under the original-bets-only rule
+/// * `Double` - The extra bet placed to double down
+/// * `Split` - The bet placed on the new hand created by a split
+/// * `Insurance` - An insurance side bet
+/// * `Payout` - A winning hand, natural, even money or insurance bet, paid
+///   together with the returned stake
+/// * `PushRefund` - The stake returned on a push
+/// * `Surrender` - Half the bet returned on surrender
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum EntryKind {
+    Wager,
+    Refund,
+    Double,
+    Split,
+    Insurance,
+    Payout,
+    PushRefund,
+    Surrender,
+}
+
+impl EntryKind {
+    /// Returns the kind of entry that records the money returned to a hand
+    /// settled with `outcome`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::hand::HandOutcome;
+    /// use blackjack_engine::ledger::EntryKind;
+    /// assert_eq!(EntryKind::for_outcome(&HandOutcome::Blackjack), EntryKind::Payout);
+    /// assert_eq!(EntryKind::for_outcome(&HandOutcome::Push), EntryKind::PushRefund);
+    /// ```
+    pub fn for_outcome(outcome: &HandOutcome) -> EntryKind {
+        match outcome {
+            HandOutcome::Win | HandOutcome::Blackjack => EntryKind::Payout,
+            HandOutcome::Push => EntryKind::PushRefund,
+            HandOutcome::Surrender => EntryKind::Surrender,
+            // Only a partial refund can accompany a lost hand
+            HandOutcome::Loss => EntryKind::Refund,
+        }
+    }
+}
+
+impl fmt::Display for EntryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            EntryKind::Wager => "WAGER",
+            EntryKind::Refund => "REFUND",
+            EntryKind::Double => "DOUBLE",
+            EntryKind::Split => "SPLIT",
+            EntryKind::Insurance => "INSURANCE",
+            EntryKind::Payout => "PAYOUT",
+            EntryKind::PushRefund => "PUSH REFUND",
+            EntryKind::Surrender => "SURRENDER",
+        };
+        f.write_str(label)
+    }
+}
+
+/// A single change to a player's bankroll.
+///
+/// Money leaving the bankroll, such as a wager, has a negative `amount`;
+/// money paid back to it has a positive one. Summing every entry gives the
+/// player's net result, so a session can be reconciled against the bankroll.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LedgerEntry {
+    /// The round the change happened in, counting from 1
+    pub round: u64,
+    /// Why the bankroll changed
+    pub kind: EntryKind,
+    /// The signed change to the bankroll
+    pub amount: Money,
+    /// The betting spot of the hand the change belongs to
+    pub spot: usize,
+    /// The index of that hand in the player's hands at the time
+    pub hand_index: usize,
+    /// The bankroll after the change
+    pub balance: Money,
+}
+
+impl fmt::Display for LedgerEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Round {} hand {} (spot {}): {} {} -> {}",
+            self.round, self.hand_index, self.spot, self.kind, self.amount, self.balance
+        )
+    }
+}
@@ -1,6 +1,13 @@
 pub mod card;
 pub mod shoe;
+pub mod shuffle;
 pub mod player;
 pub mod hand;
 pub mod game_settings;
-pub mod game;
\ No newline at end of file
+pub mod game;
+pub mod error;
+pub mod money;
+pub mod ledger;
+pub mod history;
+pub mod event;
+pub mod replay;
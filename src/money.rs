@@ -0,0 +1,216 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use serde::Serialize;
+
+/// Number of minor units (cents) in one major unit.
+pub const MINOR_UNITS: i64 = 100;
+
+/// An exact amount of money, counted in integer minor units such as cents.
+///
+/// Bets, bankrolls and payouts are all `Money`, so settling millions of
+/// rounds never drifts the way floating point sums do. Amounts that do not
+/// come out to a whole number of units, such as a 3:2 payout on an odd bet,
+/// are settled with an explicit [`Rounding`] rule.
+///
+/// Serializes as the integer number of minor units.
+///
+/// # Examples
+///
+/// ```
+/// use blackjack_engine::money::Money;
+/// let bet = Money::from_major(25);
+/// assert_eq!(bet.minor_units(), 2_500);
+/// assert_eq!((bet + Money::from_minor(50)).to_string(), "25.50");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct Money(i64);
+
+impl Money {
+    /// No money at all.
+    pub const ZERO: Money = Money(0);
+
+    /// Creates an amount from a number of minor units (cents).
+    pub const fn from_minor(units: i64) -> Money {
+        Money(units)
+    }
+
+    /// Creates an amount from a whole number of major units (dollars).
+    pub const fn from_major(amount: i64) -> Money {
+        Money(amount * MINOR_UNITS)
+    }
+
+    /// Returns the amount as a number of minor units.
+    pub const fn minor_units(self) -> i64 {
+        self.0
+    }
+
+    /// Returns true if the amount is greater than zero.
+    pub fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+
+    /// Returns true if the amount is a whole multiple of `unit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::money::Money;
+    /// let quarter = Money::from_minor(25);
+    /// assert!(Money::from_minor(150).is_multiple_of(quarter));
+    /// assert!(!Money::from_minor(160).is_multiple_of(quarter));
+    /// ```
+    pub fn is_multiple_of(self, unit: Money) -> bool {
+        unit.0 != 0 && self.0 % unit.0 == 0
+    }
+
+    /// Returns the amount multiplied by `numerator / denominator`, settling
+    /// any remainder with `rounding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::money::{Money, Rounding};
+    /// let bet = Money::from_minor(5);
+    /// assert_eq!(bet.times_ratio(3, 2, Rounding::Down), Money::from_minor(7));
+    ///
+    /// // 6:5 on $7 is $8.40, so with $1 chips the player is paid $8
+    /// let chip = Rounding::Breakage { chip: Money::from_major(1) };
+    /// assert_eq!(Money::from_major(7).times_ratio(6, 5, chip), Money::from_major(8));
+    /// ```
+    pub fn times_ratio(self, numerator: i64, denominator: i64, rounding: Rounding) -> Money {
+        let scaled = self.0 as i128 * numerator as i128;
+        let denominator = denominator as i128;
+        let units = match rounding {
+            Rounding::Down => scaled.div_euclid(denominator),
+            Rounding::Breakage { chip } if chip.0 > 0 => {
+                // Round the exact amount down to a whole chip
+                scaled.div_euclid(chip.0 as i128 * denominator) * chip.0 as i128
+            }
+            Rounding::Breakage { .. } => scaled.div_euclid(denominator),
+        };
+        Money(units as i64)
+    }
+}
+
+impl fmt::Display for Money {
+    /// Formats the amount in major units with two decimal places, e.g. `12.50`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let units = self.0.unsigned_abs();
+        let minor = MINOR_UNITS as u64;
+        write!(f, "{}{}.{:02}", sign, units / minor, units % minor)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        self.0 -= other.0;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+
+impl Mul<i64> for Money {
+    type Output = Money;
+
+    fn mul(self, factor: i64) -> Money {
+        Money(self.0 * factor)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
+/// Determines how a payout that does not come out even is settled.
+///
+/// * `Down` - The payout is rounded down to the nearest minor unit, and the
+///   fraction of a cent stays with the house
+/// * `Breakage { chip }` - Payouts are made in whole chips of `chip`. Any
+///   remainder smaller than a chip is kept by the house as breakage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Breakage { chip: Money },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut amount = Money::from_major(10);
+        amount += Money::from_minor(25);
+        amount -= Money::from_minor(50);
+        assert_eq!(amount, Money::from_minor(975));
+        assert_eq!(amount * 2, Money::from_minor(1_950));
+        assert_eq!(-amount, Money::from_minor(-975));
+        assert_eq!([amount, amount].iter().sum::<Money>(), Money::from_minor(1_950));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Money::from_major(5_000).to_string(), "5000.00");
+        assert_eq!(Money::from_minor(7).to_string(), "0.07");
+        assert_eq!(Money::from_minor(-1_250).to_string(), "-12.50");
+    }
+
+    #[test]
+    fn test_rounding_down() {
+        assert_eq!(Money::from_minor(1_001).times_ratio(3, 2, Rounding::Down), Money::from_minor(1_501));
+        assert_eq!(Money::from_minor(1_000).times_ratio(6, 5, Rounding::Down), Money::from_minor(1_200));
+        assert_eq!(Money::from_minor(1).times_ratio(1, 2, Rounding::Down), Money::ZERO);
+    }
+
+    #[test]
+    fn test_rounding_with_breakage_chip() {
+        let breakage = Rounding::Breakage { chip: Money::from_minor(50) };
+        // 3:2 on $5 is exactly $7.50
+        assert_eq!(Money::from_major(5).times_ratio(3, 2, breakage), Money::from_minor(750));
+        // 3:2 on $5.25 is $7.875, and the house keeps the 37.5 cents of breakage
+        assert_eq!(Money::from_minor(525).times_ratio(3, 2, breakage), Money::from_minor(750));
+        // Half of $15 is $7.50
+        assert_eq!(Money::from_major(15).times_ratio(1, 2, breakage), Money::from_minor(750));
+        // Half of $7 is $3.50, paid as $3 in whole dollar chips
+        assert_eq!(Money::from_major(7).times_ratio(1, 2, Rounding::Breakage { chip: Money::from_major(1) }), Money::from_major(3));
+    }
+}
@@ -1,18 +1,33 @@
 use crate::card::Card;
-use crate::hand::Hand;
+use crate::hand::{Hand, HandOutcome};
+use crate::history::RoundHistory;
+use crate::ledger::{EntryKind, LedgerEntry};
+use crate::money::Money;
+
+/// Bankroll a player starts with unless told otherwise.
+pub const DEFAULT_BANKROLL: Money = Money::from_major(10_000);
 
 /// Represents a player in a blackjack game.
 ///
 /// A player can have multiple hands (due to splits) and maintains a bankroll
 /// to track their available funds. The player structure manages the state
 /// of all active hands and their total money.
+///
+/// Every change the game makes to the bankroll goes through
+/// [`Player::record`], which keeps a ledger of the changes for auditing.
 pub struct Player {
-    /// The player's active hands. Usually just one hand, but can have multiple after splitting.
+    /// The player's name
+    pub name: String,
+    /// The seat the player sits in at the table, counting from 0
+    pub seat: usize,
+    /// The player's active hands. Usually just one hand, but can have multiple after splitting
+    /// or when betting on several spots. Each hand records the spot it is played on.
     pub hands: Vec<Hand>,
-    /// The player's available money for betting
-    pub bank_roll: f64
-    // I'll eventually want to track previous_hands, but not necessary yet
-    // pub previous_hands: Vec<Hand>,
+    /// The player's available money for betting, changed only through
+    /// [`Player::record`]
+    bank_roll: Money,
+    /// Every recorded change to the bankroll, oldest first
+    ledger: Vec<LedgerEntry>,
 }
 
 impl Player {
@@ -24,14 +39,18 @@ impl Player {
     ///
     /// ```
     /// use blackjack_engine::player::Player;
+    /// use blackjack_engine::money::Money;
     /// let player = Player::new();
-    /// assert_eq!(player.bank_roll, 10_000f64);
+    /// assert_eq!(player.bank_roll(), Money::from_major(10_000));
     /// assert_eq!(player.hands.len(), 1);
     /// ```
     pub fn new() -> Player {
         Player {
+            name: String::new(),
+            seat: 0,
             hands: vec![Hand::new()],
-            bank_roll: 10_000f64
+            bank_roll: DEFAULT_BANKROLL,
+            ledger: Vec::new(),
         }
     }
 
@@ -43,13 +62,35 @@ impl Player {
     ///
     /// ```
     /// use blackjack_engine::player::Player;
-    /// let player = Player::with_bankroll(5_000f64);
-    /// assert_eq!(player.bank_roll, 5_000f64);
+    /// use blackjack_engine::money::Money;
+    /// let player = Player::with_bankroll(Money::from_major(5_000));
+    /// assert_eq!(player.bank_roll(), Money::from_major(5_000));
     /// ```
-    pub fn with_bankroll(bankroll: f64) -> Player {
+    pub fn with_bankroll(bankroll: Money) -> Player {
         Player {
+            name: String::new(),
+            seat: 0,
             hands: vec![Hand::new()],
-            bank_roll: bankroll
+            bank_roll: bankroll,
+            ledger: Vec::new(),
+        }
+    }
+
+    /// Creates a new named player with the default bankroll of 10,000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::player::Player;
+    /// use blackjack_engine::money::Money;
+    /// let player = Player::with_name("Alice".to_string());
+    /// assert_eq!(player.name, "Alice");
+    /// assert_eq!(player.bank_roll(), Money::from_major(10_000));
+    /// ```
+    pub fn with_name(name: String) -> Player {
+        Player {
+            name,
+            ..Player::new()
         }
     }
 
@@ -100,6 +141,97 @@ impl Player {
         self.hands = vec![Hand::new()]
     }
 
+    /// Returns the player's available money for betting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::player::Player;
+    /// use blackjack_engine::money::Money;
+    /// let player = Player::with_bankroll(Money::from_major(500));
+    /// assert_eq!(player.bank_roll(), Money::from_major(500));
+    /// ```
+    pub fn bank_roll(&self) -> Money {
+        self.bank_roll
+    }
+
+    /// Changes the bankroll by `amount` and records the change in the ledger
+    /// against the hand at `hand_index` in `round`.
+    ///
+    /// Amounts are signed: negative for money staked, positive for money
+    /// paid back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::ledger::EntryKind;
+    /// use blackjack_engine::money::Money;
+    /// use blackjack_engine::player::Player;
+    /// let mut player = Player::new();
+    /// player.record(EntryKind::Wager, -Money::from_major(100), 0, 1);
+    /// assert_eq!(player.bank_roll(), Money::from_major(9_900));
+    /// assert_eq!(player.ledger()[0].balance, Money::from_major(9_900));
+    /// ```
+    pub fn record(&mut self, kind: EntryKind, amount: Money, hand_index: usize, round: u64) {
+        self.bank_roll += amount;
+        let spot = self.hands.get(hand_index).map_or(0, |hand| hand.spot);
+        self.ledger.push(LedgerEntry {
+            round,
+            kind,
+            amount,
+            spot,
+            hand_index,
+            balance: self.bank_roll,
+        });
+    }
+
+    /// Settles the hand at `hand_index` with `outcome`, paying `returned`
+    /// back to the bankroll. Any payment is recorded with the entry kind
+    /// that matches the outcome.
+    ///
+    /// A hand is settled only once: if it already has an outcome, nothing
+    /// changes and false is returned.
+    pub fn settle_hand(&mut self, hand_index: usize, outcome: HandOutcome, returned: Money, round: u64) -> bool {
+        if self.hands[hand_index].outcome.is_some() {
+            return false;
+        }
+        if returned.is_positive() {
+            self.record(EntryKind::for_outcome(&outcome), returned, hand_index, round);
+        }
+        self.hands[hand_index].outcome = Some(outcome);
+        true
+    }
+
+    /// Returns the settled hands the player played from their seat in the
+    /// rounds still held in `history`, oldest first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blackjack_engine::game::Game;
+    /// use blackjack_engine::game_settings::GameSettings;
+    /// let game = Game::new(GameSettings::default_single_player("Alice".to_string())).unwrap();
+    /// assert_eq!(game.players[0].previous_hands(game.history()).count(), 0);
+    /// ```
+    pub fn previous_hands<'a>(&self, history: &'a RoundHistory) -> impl Iterator<Item = &'a Hand> + 'a {
+        history.previous_hands(self.seat)
+    }
+
+    /// Returns every recorded change to the bankroll, oldest first.
+    pub fn ledger(&self) -> &[LedgerEntry] {
+        &self.ledger
+    }
+
+    /// Returns the ledger entries recorded in `round`.
+    pub fn round_entries(&self, round: u64) -> impl Iterator<Item = &LedgerEntry> {
+        self.ledger.iter().filter(move |entry| entry.round == round)
+    }
+
+    /// Returns the net change to the bankroll over `round`.
+    pub fn round_net(&self, round: u64) -> Money {
+        self.round_entries(round).map(|entry| entry.amount).sum()
+    }
+
     /// Prints the current state of all active hands to the console.
     ///
     /// Each hand is numbered starting from 1, and all cards in each hand
@@ -119,13 +251,19 @@ impl Player {
         for (i, hand) in self.hands.iter().enumerate() {
             print!("Hand {}: ", i + 1);
             for card in hand.cards.iter() {
-                print!("{} ", card.to_string());
+                print!("{} ", card);
             }
             println!("\n");
         }
     }
 }
 
+impl Default for Player {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,7 +273,7 @@ mod tests {
     fn test_new_player() {
         let player = Player::new();
         assert_eq!(player.hands.len(), 1);
-        assert_eq!(player.bank_roll, 10_000f64);
+        assert_eq!(player.bank_roll(), Money::from_major(10_000));
         assert_eq!(player.hands[0].cards.len(), 0);
     }
 
@@ -182,4 +320,32 @@ mod tests {
         player.reset_hands();
         assert_eq!(player.hands[0].cards.len(), 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_ledger_records_bankroll_changes() {
+        let mut player = Player::new();
+        player.hands[0].bet = Money::from_major(100);
+        player.record(EntryKind::Wager, -Money::from_major(100), 0, 1);
+        player.settle_hand(0, HandOutcome::Win, Money::from_major(200), 1);
+        player.reset_hands();
+        player.record(EntryKind::Wager, -Money::from_major(50), 0, 2);
+        player.settle_hand(0, HandOutcome::Loss, Money::ZERO, 2);
+
+        assert_eq!(player.ledger().len(), 3);
+        assert_eq!(player.ledger()[1].kind, EntryKind::Payout);
+        assert_eq!(player.hands[0].outcome, Some(HandOutcome::Loss));
+        assert_eq!(player.round_net(1), Money::from_major(100));
+        assert_eq!(player.round_net(2), -Money::from_major(50));
+        assert_eq!(player.bank_roll(), Money::from_major(10_050));
+    }
+
+    #[test]
+    fn test_hand_settled_once() {
+        let mut player = Player::new();
+        assert!(player.settle_hand(0, HandOutcome::Loss, Money::ZERO, 1));
+        assert!(!player.settle_hand(0, HandOutcome::Push, Money::from_major(100), 1));
+
+        assert_eq!(player.hands[0].outcome, Some(HandOutcome::Loss));
+        assert!(player.ledger().is_empty());
+    }
+}
@@ -0,0 +1,350 @@
+use std::fmt;
+use serde::Serialize;
+use crate::card::Card;
+use crate::error::{EngineError, ReplayError};
+use crate::game::{Game, GameAction};
+use crate::game_settings::GameSettings;
+use crate::money::Money;
+use crate::shoe::Shoe;
+
+/// Where a replayed game's cards come from.
+///
+/// * `Seed(seed)` - A shoe shuffled from `seed`, as [`Game::with_seed`]
+///   creates. Shuffles replay exactly.
+/// * `Cards(cards)` - A stacked shoe dealing `cards` in the order given.
+///   Stacked shoes are never reshuffled automatically, and a `Shuffle`
+///   command on one uses the stacked shoe's fixed seed, so it replays too.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum ReplayShoe {
+    Seed(u64),
+    Cards(Vec<Card>),
+}
+
+/// One call made on a [`Game`], recorded so it can be replayed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Command {
+    /// Bets placed by a seat, one per spot, through [`Game::accept_user_bet`]
+    Bet { seat: usize, bets: Vec<Money> },
+    /// The initial deal, through [`Game::deal_initial_cards`]
+    Deal,
+    /// A player's action, through [`Game::process_player_action`]
+    Action { seat: usize, hand_index: usize, action: GameAction },
+    /// One step of the dealer's turn, through [`Game::next_dealer_turn`]
+    DealerStep,
+    /// The start of the next round, through [`Game::next_round`]
+    NextRound,
+    /// A shuffle of the shoe, through [`Game::shuffle_shoe`]
+    Shuffle,
+}
+
+impl Command {
+    /// Makes the call this command records on `game`.
+    pub fn apply(&self, game: &mut Game) -> Result<(), EngineError> {
+        match self {
+            Command::Bet { seat, bets } => game.accept_user_bet(*seat, bets),
+            Command::Deal => game.deal_initial_cards(),
+            Command::Action { seat, hand_index, action } => game.process_player_action(*seat, *action, *hand_index),
+            Command::DealerStep => game.next_dealer_turn(),
+            Command::NextRound => game.next_round(),
+            Command::Shuffle => game.shuffle_shoe(),
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Bet { seat, bets } => {
+                let bets: Vec<String> = bets.iter().map(|bet| bet.to_string()).collect();
+                write!(f, "Seat {} bets {}", seat, bets.join(", "))
+            }
+            Command::Deal => f.write_str("Deal"),
+            Command::Action { seat, hand_index, action } => write!(f, "Seat {} hand {}: {}", seat, hand_index, action),
+            Command::DealerStep => f.write_str("Dealer step"),
+            Command::NextRound => f.write_str("Next round"),
+            Command::Shuffle => f.write_str("Shuffle"),
+        }
+    }
+}
+
+/// Rebuilds a game from its settings, its shoe and the commands made on it,
+/// to settle disputes and reproduce bugs.
+///
+/// Step `n` is the game after the first `n` commands, so step 0 is the
+/// freshly created game. The replay sits at one step at a time and can move
+/// forwards or backwards; moving backwards replays the commands from the
+/// start, since every step is determined by the ones before it.
+///
+/// Apart from the shoe, the game starts as its settings describe, including
+/// the [starting bankroll](GameSettings::starting_bankroll) and the
+/// [shuffle procedure](GameSettings::shuffle_procedure).
+///
+/// # Examples
+///
+/// ```
+/// use blackjack_engine::game::{GameAction, GamePhase};
+/// use blackjack_engine::game_settings::GameSettings;
+/// use blackjack_engine::money::Money;
+/// use blackjack_engine::replay::{Command, Replay, ReplayShoe};
+/// let settings = GameSettings::default_single_player("Player1".to_string());
+/// let cards = "Ts 6h 7d Kc 9s".split(' ').map(|card| card.parse().unwrap()).collect();
+/// let mut replay = Replay::new(settings, ReplayShoe::Cards(cards), vec![
+///     Command::Bet { seat: 0, bets: vec![Money::from_major(10)] },
+///     Command::Deal,
+///     Command::Action { seat: 0, hand_index: 0, action: GameAction::Hit },
+/// ]).unwrap();
+///
+/// replay.seek(3).unwrap();
+/// assert_eq!(replay.game().players[0].hands[0].best_value(), 26);
+/// replay.step_back().unwrap();
+/// assert_eq!(replay.game().get_state().phase(), GamePhase::PlayerTurn);
+/// assert_eq!(replay.game().players[0].hands[0].best_value(), 17);
+/// ```
+pub struct Replay {
+    settings: GameSettings,
+    shoe: ReplayShoe,
+    commands: Vec<Command>,
+    /// The game at the current step
+    game: Game,
+    /// The number of commands applied to the current game
+    step: usize,
+}
+
+impl Replay {
+    /// Creates a replay of `commands` on a game with `settings`, dealing
+    /// from `shoe`. The replay starts at step 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EngineError::InvalidSettings` if the settings fail validation.
+    pub fn new(settings: GameSettings, shoe: ReplayShoe, commands: Vec<Command>) -> Result<Replay, EngineError> {
+        let game = Replay::start(&settings, &shoe)?;
+        Ok(Replay { settings, shoe, commands, game, step: 0 })
+    }
+
+    /// Creates the game as it was before any command.
+    fn start(settings: &GameSettings, shoe: &ReplayShoe) -> Result<Game, EngineError> {
+        let builder = Game::builder(settings.clone());
+        match shoe {
+            ReplayShoe::Seed(seed) => builder.seed(*seed),
+            ReplayShoe::Cards(cards) => builder.shoe(Shoe::from_cards(cards.clone())),
+        }
+        .build()
+    }
+
+    /// Returns the commands being replayed, in order.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Adds a command to the end of the replay, so a game can be recorded
+    /// as it is played.
+    pub fn push(&mut self, command: Command) {
+        self.commands.push(command);
+    }
+
+    /// Returns the current step: the number of commands applied so far.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the game at the current step.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Applies the next command. Returns false, changing nothing, if every
+    /// command has been applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReplayError`] if the game refuses the command, in which
+    /// case the replay stays at the current step.
+    pub fn step_forward(&mut self) -> Result<bool, ReplayError> {
+        let Some(command) = self.commands.get(self.step) else {
+            return Ok(false);
+        };
+        if let Err(error) = command.apply(&mut self.game) {
+            // A refused command can leave a partly applied change behind
+            self.game = self.game_at(self.step)?;
+            return Err(ReplayError { command: self.step, error });
+        }
+        self.step += 1;
+        Ok(true)
+    }
+
+    /// Moves back to the previous step. Returns false, changing nothing, at
+    /// step 0.
+    pub fn step_back(&mut self) -> Result<bool, ReplayError> {
+        if self.step == 0 {
+            return Ok(false);
+        }
+        self.seek(self.step - 1)?;
+        Ok(true)
+    }
+
+    /// Moves to `step`, or to the last step if there are fewer commands.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReplayError`] if the game refuses a command on the way,
+    /// in which case the replay stops at the step before that command.
+    pub fn seek(&mut self, step: usize) -> Result<(), ReplayError> {
+        let step = step.min(self.commands.len());
+        if step < self.step {
+            self.game = self.game_at(0)?;
+            self.step = 0;
+        }
+        while self.step < step {
+            self.step_forward()?;
+        }
+        Ok(())
+    }
+
+    /// Returns a new game at `step`, leaving the replay where it is. Steps
+    /// past the last command give the game after every command.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReplayError`] if the game refuses one of the commands.
+    pub fn game_at(&self, step: usize) -> Result<Game, ReplayError> {
+        let mut game = Replay::start(&self.settings, &self.shoe)
+            .map_err(|error| ReplayError { command: 0, error })?;
+        for (index, command) in self.commands.iter().take(step).enumerate() {
+            command.apply(&mut game).map_err(|error| ReplayError { command: index, error })?;
+        }
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{GamePhase, GameState};
+    use crate::shuffle::ShuffleProcedure;
+
+    /// Applies `command` to `game` and records it.
+    fn play(game: &mut Game, commands: &mut Vec<Command>, command: Command) {
+        command.apply(game).unwrap();
+        commands.push(command);
+    }
+
+    #[test]
+    fn test_seeded_game_replays_exactly() {
+        let settings = GameSettings::default_single_player("Player1".to_string());
+        let mut game = Game::with_seed(settings.clone(), 2024).unwrap();
+        let mut commands = Vec::new();
+        play(&mut game, &mut commands, Command::Shuffle);
+        for _ in 0..5 {
+            play(&mut game, &mut commands, Command::Bet { seat: 0, bets: vec![Money::from_major(25)] });
+            play(&mut game, &mut commands, Command::Deal);
+            loop {
+                let command = match game.get_state() {
+                    GameState::InsuranceOffered { active_seat, active_hand_index, .. } => Command::Action {
+                        seat: *active_seat,
+                        hand_index: *active_hand_index,
+                        action: GameAction::DeclineInsurance,
+                    },
+                    GameState::PlayerTurn { active_seat, active_hand_index, .. } => {
+                        let hit = game.players[*active_seat].hands[*active_hand_index].best_value() < 15;
+                        Command::Action {
+                            seat: *active_seat,
+                            hand_index: *active_hand_index,
+                            action: if hit { GameAction::Hit } else { GameAction::Stand },
+                        }
+                    }
+                    GameState::DealerTurn { .. } => Command::DealerStep,
+                    _ => break,
+                };
+                play(&mut game, &mut commands, command);
+            }
+            play(&mut game, &mut commands, Command::NextRound);
+        }
+
+        let count = commands.len();
+        let mut replay = Replay::new(settings, ReplayShoe::Seed(2024), commands).unwrap();
+        replay.seek(count).unwrap();
+        assert_eq!(replay.step(), count);
+        assert!(!replay.step_forward().unwrap());
+        assert_eq!(replay.game().players[0].bank_roll(), game.players[0].bank_roll());
+        assert_eq!(replay.game().players[0].ledger(), game.players[0].ledger());
+        assert_eq!(replay.game().shoe.cards, game.shoe.cards);
+        assert_eq!(replay.game().history(), game.history());
+    }
+
+    #[test]
+    fn test_replay_keeps_shuffle_procedure_and_bankroll() {
+        let mut settings = GameSettings::default_single_player("Player1".to_string());
+        settings.starting_bankroll = Money::from_major(500);
+        settings.shuffle_procedure = ShuffleProcedure::hand_shuffle();
+        let mut game = Game::with_seed(settings.clone(), 7).unwrap();
+        let mut commands = Vec::new();
+        play(&mut game, &mut commands, Command::Shuffle);
+        play(&mut game, &mut commands, Command::Bet { seat: 0, bets: vec![Money::from_major(100)] });
+        play(&mut game, &mut commands, Command::Deal);
+
+        let mut replay = Replay::new(settings, ReplayShoe::Seed(7), commands).unwrap();
+        replay.seek(3).unwrap();
+        assert_eq!(replay.game().shoe.cards, game.shoe.cards);
+        assert_eq!(replay.game().players[0].hands[0].cards, game.players[0].hands[0].cards);
+        assert_eq!(replay.game().players[0].bank_roll(), Money::from_major(400));
+    }
+
+    #[test]
+    fn test_stacked_shoe_shuffle_replays() {
+        let settings = GameSettings::default_single_player("Player1".to_string());
+        let cards: Vec<Card> = "Ts 6h 7d Kc 9s 2c 3d 4h".split(' ').map(|card| card.parse().unwrap()).collect();
+        let mut replay = Replay::new(settings, ReplayShoe::Cards(cards), vec![Command::Shuffle]).unwrap();
+        replay.seek(1).unwrap();
+        let shuffled = replay.game().shoe.cards.clone();
+
+        replay.step_back().unwrap();
+        replay.seek(1).unwrap();
+        assert_eq!(replay.game().shoe.cards, shuffled);
+    }
+
+    #[test]
+    fn test_step_backwards_through_scripted_round() {
+        let mut settings = GameSettings::default_single_player("Player1".to_string());
+        settings.dealer_hits_soft_17 = true;
+        let cards = "8s 6h 8d Ac 3c Th 5d Kc Ks".split(' ').map(|card| card.parse().unwrap()).collect();
+        let mut replay = Replay::new(settings, ReplayShoe::Cards(cards), vec![
+            Command::Bet { seat: 0, bets: vec![Money::from_major(100)] },
+            Command::Deal,
+            Command::Action { seat: 0, hand_index: 0, action: GameAction::Split },
+            Command::Action { seat: 0, hand_index: 0, action: GameAction::Double },
+            Command::Action { seat: 0, hand_index: 1, action: GameAction::Hit },
+            Command::DealerStep,
+            Command::DealerStep,
+        ]).unwrap();
+
+        replay.seek(usize::MAX).unwrap();
+        assert_eq!(replay.step(), 7);
+        assert_eq!(replay.game().get_state().phase(), GamePhase::RoundComplete);
+        assert_eq!(replay.game().players[0].bank_roll(), Money::from_major(10_100));
+
+        replay.seek(3).unwrap();
+        assert_eq!(replay.game().players[0].hands.len(), 2);
+        assert_eq!(replay.game().players[0].hands[0].best_value(), 11);
+        assert!(replay.step_back().unwrap());
+        assert_eq!(replay.game().players[0].hands.len(), 1);
+        assert_eq!(replay.game_at(0).unwrap().get_state().phase(), GamePhase::WaitingForBet);
+        assert_eq!(replay.step(), 2);
+    }
+
+    #[test]
+    fn test_refused_command_reports_its_index() {
+        let settings = GameSettings::default_single_player("Player1".to_string());
+        let mut replay = Replay::new(settings, ReplayShoe::Seed(1), vec![
+            Command::Bet { seat: 0, bets: vec![Money::from_major(10)] },
+            Command::DealerStep,
+        ]).unwrap();
+
+        let error = replay.seek(2).unwrap_err();
+        assert_eq!(error.command, 1);
+        assert_eq!(error.error.code(), "WRONG_PHASE");
+        assert_eq!(replay.step(), 1);
+        assert_eq!(replay.game().get_state().phase(), GamePhase::WaitingToDeal);
+        assert_eq!(Command::DealerStep.to_string(), "Dealer step");
+    }
+}
@@ -1,28 +1,85 @@
-use std::thread::sleep;
-use std::time::Duration;
 use strum::IntoEnumIterator;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::{RngCore, SeedableRng};
 use crate::card::{Card, Rank, Suit};
+use crate::error::ParseCardError;
+use crate::shuffle::ShuffleProcedure;
+
+/// Percentage of the shoe dealt before the cut card comes out, unless set
+/// otherwise with [`Shoe::set_penetration`].
+pub const DEFAULT_PENETRATION: u8 = 75;
+
+/// Determines when dealt cards go back into the shoe.
+///
+/// * `CutCard` - A hand-shuffled shoe: cards stay in the discard tray until
+///   the cut card comes out and the whole shoe is reshuffled
+/// * `Continuous` - A continuous shuffling machine (CSM): at the end of each
+///   round the discards go straight back into the machine. The next
+///   `buffer` cards have already left the machine to be dealt, so the
+///   returned cards are shuffled in beneath them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShoeMode {
+    CutCard,
+    Continuous { buffer: usize },
+}
 
 /// Represents a dealer's shoe in a casino blackjack game.
 ///
-/// A shoe contains multiple decks of cards and tracks both the active cards
-/// and discarded cards. This implementation mirrors real casino practices
+/// A shoe contains multiple decks of cards and tracks where every card is:
+/// still in the shoe, in play on the table this round, or in the discard
+/// tray. This implementation mirrors real casino practices
 /// where multiple decks are shuffled together to make card counting more difficult.
+///
+/// Each shoe owns the random number generator it shuffles with. A shoe
+/// created from a seed shuffles identically every time, so games can be
+/// reproduced.
+///
+/// A cut card is placed at a set penetration. Once it has come out the shoe
+/// reports [`Shoe::cut_card_reached`], and the game reshuffles it before the
+/// next round.
+///
+/// Cards burned after a shuffle go face down into the discard tray. The shoe
+/// keeps them apart from the cards dealt to hands, so counting and tracking
+/// tools can tell what was actually seen; see [`Shoe::burned_cards`] and
+/// [`Shoe::dealt_cards`].
+///
+/// In [`ShoeMode::Continuous`] there is no cut card; the discards are
+/// shuffled back in after every round instead.
+///
+/// Shuffles follow the shoe's [`ShuffleProcedure`], a perfect random shuffle
+/// unless another procedure is set with [`Shoe::set_shuffle_procedure`].
 pub struct Shoe {
     /// Cards currently available to be dealt
     pub cards: Vec<Card>,
-    /// Cards that have been dealt and discarded
+    /// Cards dealt this round that are still on the table
+    pub in_play: Vec<Card>,
+    /// The discard tray: burned cards face down at the front, followed by
+    /// the cards dealt in earlier rounds
     pub discarded: Vec<Card>,
+    /// Number of burned cards at the front of the discard tray
+    burned: usize,
     /// Number of complete decks in the shoe
-    number_of_decks: usize
+    number_of_decks: usize,
+    /// Random number generator used for every shuffle
+    rng: Box<dyn RngCore + Send>,
+    /// The steps the dealer follows to shuffle
+    procedure: ShuffleProcedure,
+    /// Number of cards left in the shoe when the cut card comes out
+    cut_card: usize,
+    /// When dealt cards return to the shoe
+    mode: ShoeMode,
+    /// True for a shoe built from a scripted card order, which is never
+    /// refilled automatically
+    stacked: bool,
 }
 
 impl Shoe {
     /// Creates a new shoe with the specified number of decks.
     ///
     /// The shoe is created with all cards in order (unshuffled). Cards
-    /// are organized by rank and suit, repeated for each deck.
+    /// are organized by rank and suit, repeated for each deck. Shuffles use
+    /// a generator seeded from the operating system.
     ///
     /// # Arguments
     ///
@@ -31,10 +88,119 @@ impl Shoe {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::shoe::Shoe;
     /// let shoe = Shoe::new(6); // Creates a 6-deck shoe (312 cards)
     /// assert_eq!(shoe.cards.len(), 312);
     /// ```
     pub fn new(num_decks: usize) -> Self {
+        Shoe::with_rng(num_decks, StdRng::from_os_rng())
+    }
+
+    /// Creates a new shoe whose shuffles are determined by `seed`.
+    ///
+    /// Two shoes with the same seed and deck count shuffle into the same
+    /// order, as long as they are built with the same version of the crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::shoe::Shoe;
+    /// let mut first = Shoe::with_seed(6, 42);
+    /// let mut second = Shoe::with_seed(6, 42);
+    /// first.shuffle();
+    /// second.shuffle();
+    /// assert_eq!(first.cards, second.cards);
+    /// ```
+    pub fn with_seed(num_decks: usize, seed: u64) -> Self {
+        Shoe::with_rng(num_decks, StdRng::seed_from_u64(seed))
+    }
+
+    /// Creates a new shoe that shuffles with the given random number generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::shoe::Shoe;
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// let mut shoe = Shoe::with_rng(1, SmallRng::seed_from_u64(3));
+    /// shoe.shuffle();
+    /// assert_eq!(shoe.cards.len(), 52);
+    /// ```
+    pub fn with_rng<R: RngCore + Send + 'static>(num_decks: usize, rng: R) -> Self {
+        Shoe {
+            cards: Shoe::ordered_cards(num_decks),
+            in_play: Vec::new(),
+            discarded: Vec::with_capacity(52 * num_decks),
+            burned: 0,
+            number_of_decks: num_decks,
+            rng: Box::new(rng),
+            procedure: ShuffleProcedure::uniform(),
+            cut_card: Shoe::cut_card_position(num_decks, DEFAULT_PENETRATION),
+            mode: ShoeMode::CutCard,
+            stacked: false,
+        }
+    }
+
+    /// Creates a stacked shoe that deals exactly `cards`, in the order given.
+    ///
+    /// The first card in the list is the first card dealt. A stacked shoe is
+    /// never refilled when it runs low, so scenario tests can script every
+    /// draw; drawing past the end of the script returns `None`. Shuffling a
+    /// stacked shoe uses a fixed seed, so it always gives the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::shoe::Shoe;
+    /// let mut shoe = Shoe::from_cards(vec![
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::King, Suit::Diamonds),
+    /// ]);
+    /// assert_eq!(shoe.draw_card(), Some(Card::new(Rank::Ace, Suit::Spades)));
+    /// ```
+    pub fn from_cards(cards: Vec<Card>) -> Self {
+        let number_of_decks = cards.len().div_ceil(52);
+        Shoe {
+            in_play: Vec::new(),
+            discarded: Vec::with_capacity(cards.len()),
+            burned: 0,
+            // Cards are drawn from the end
+            cards: cards.into_iter().rev().collect(),
+            number_of_decks,
+            rng: Box::new(StdRng::seed_from_u64(0)),
+            procedure: ShuffleProcedure::uniform(),
+            cut_card: 0,
+            mode: ShoeMode::CutCard,
+            stacked: true,
+        }
+    }
+
+    /// Creates a stacked shoe from cards written in short notation and
+    /// separated by whitespace, such as `"As Kd 8c 8h"`, in deal order.
+    ///
+    /// See [`Shoe::from_cards`] for how a stacked shoe behaves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::card::{Card, Rank, Suit};
+    /// # use blackjack_engine::shoe::Shoe;
+    /// let mut shoe = Shoe::from_notation("As Kd 8c 8h").unwrap();
+    /// assert_eq!(shoe.cards.len(), 4);
+    /// assert_eq!(shoe.draw_card(), Some(Card::new(Rank::Ace, Suit::Spades)));
+    /// assert!(Shoe::from_notation("As Kx").is_err());
+    /// ```
+    pub fn from_notation(notation: &str) -> Result<Self, ParseCardError> {
+        let cards = notation.split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<Card>, _>>()?;
+        Ok(Shoe::from_cards(cards))
+    }
+
+    /// Returns `num_decks` decks of cards in order, organized by rank and suit.
+    fn ordered_cards(num_decks: usize) -> Vec<Card> {
         // Initialize a vector w/ size defined upfront
         let capacity = 52 * num_decks;
         let mut cards: Vec<Card> = Vec::with_capacity(capacity);
@@ -48,45 +214,199 @@ impl Shoe {
                     .collect::<Vec<Card>>()
             );
         }
+        cards
+    }
 
-        Shoe {
-            cards,
-            discarded: Vec::with_capacity(capacity),
-            number_of_decks: num_decks
+    /// Places the cut card so that `percent` of the shoe is dealt before it
+    /// comes out. Values above 100 are treated as 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::shoe::Shoe;
+    /// let mut shoe = Shoe::new(1);
+    /// shoe.set_penetration(50);
+    /// while shoe.cards.len() > 27 {
+    ///     shoe.draw_card();
+    /// }
+    /// assert!(!shoe.cut_card_reached());
+    /// shoe.draw_card();
+    /// assert!(shoe.cut_card_reached());
+    /// ```
+    pub fn set_penetration(&mut self, percent: u8) {
+        self.cut_card = Shoe::cut_card_position(self.number_of_decks, percent);
+    }
+
+    /// Returns true once the cut card has come out and the shoe should be
+    /// reshuffled before the next round. Always false for a stacked shoe or
+    /// a continuous shuffling machine.
+    pub fn cut_card_reached(&self) -> bool {
+        !self.stacked && self.mode == ShoeMode::CutCard && self.cards.len() <= self.cut_card
+    }
+
+    /// Returns when dealt cards go back into the shoe.
+    pub fn mode(&self) -> ShoeMode {
+        self.mode
+    }
+
+    /// Sets when dealt cards go back into the shoe. Takes effect the next
+    /// time cards are discarded.
+    pub fn set_mode(&mut self, mode: ShoeMode) {
+        self.mode = mode;
+    }
+
+    /// Returns true if the shoe holds enough cards to deal a round to
+    /// `num_spots` betting spots and the dealer.
+    ///
+    /// Allows for two initial cards and two further draws per hand. A
+    /// stacked shoe always reports true, since its script decides the cards.
+    pub fn has_cards_for(&self, num_spots: usize) -> bool {
+        self.stacked || self.cards.len() >= (num_spots + 1) * 2 * 2
+    }
+
+    /// Returns every card on the table and in the discard tray to the shoe
+    /// and shuffles it, leaving the cut card at the same penetration.
+    ///
+    /// Only call this between rounds, since cards still in play are
+    /// shuffled back in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::shoe::Shoe;
+    /// let mut shoe = Shoe::new(1);
+    /// shoe.draw_card();
+    /// shoe.reshuffle();
+    /// assert_eq!(shoe.cards.len(), 52);
+    /// assert!(shoe.in_play.is_empty());
+    /// ```
+    pub fn reshuffle(&mut self) {
+        // The discards are gathered on top of the unplayed cards
+        let unplayed = self.cards.len();
+        self.cards.append(&mut self.in_play);
+        self.cards.append(&mut self.discarded);
+        self.burned = 0;
+        self.procedure.apply(&mut self.cards, unplayed, &mut self.rng);
+    }
+
+    /// Burns up to `count` cards from the top of the shoe, moving them face
+    /// down into the discard tray without dealing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::shoe::Shoe;
+    /// let mut shoe = Shoe::new(1);
+    /// shoe.shuffle();
+    /// shoe.burn(1);
+    /// assert_eq!(shoe.cards.len(), 51);
+    /// assert_eq!(shoe.burned_cards().len(), 1);
+    /// assert_eq!(shoe.dealt_cards().count(), 0);
+    /// ```
+    pub fn burn(&mut self, count: usize) {
+        for _ in 0..count {
+            let Some(card) = self.cards.pop() else { break };
+            self.discarded.insert(self.burned, card);
+            self.burned += 1;
+        }
+    }
+
+    /// Moves the cards in play into the discard tray once a round is over.
+    ///
+    /// In [`ShoeMode::Continuous`] the whole tray then goes back into the
+    /// machine and is shuffled in beneath the buffered cards. A stacked
+    /// shoe keeps its discards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::shoe::{Shoe, ShoeMode};
+    /// let mut shoe = Shoe::new(1);
+    /// shoe.set_mode(ShoeMode::Continuous { buffer: 10 });
+    /// shoe.draw_card();
+    /// shoe.discard_in_play();
+    /// assert_eq!(shoe.cards.len(), 52);
+    /// assert!(shoe.discarded.is_empty());
+    /// ```
+    pub fn discard_in_play(&mut self) {
+        self.discarded.append(&mut self.in_play);
+        if let ShoeMode::Continuous { buffer } = self.mode {
+            if !self.stacked {
+                self.return_discards(buffer);
+            }
         }
     }
 
-    /// Shuffles all cards currently in the shoe.
+    /// Returns the cards burned since the last reshuffle. They were never
+    /// shown, so trackers should treat them as unseen.
+    pub fn burned_cards(&self) -> &[Card] {
+        &self.discarded[..self.burned]
+    }
+
+    /// Returns every card dealt to a hand since the last reshuffle, both
+    /// those in the discard tray and those still in play, in the order they
+    /// were dealt.
+    ///
+    /// Cards in play include the dealer's hole card, even while it is face
+    /// down.
+    pub fn dealt_cards(&self) -> impl Iterator<Item = &Card> {
+        self.discarded[self.burned..].iter().chain(self.in_play.iter())
+    }
+
+    /// Shuffles all cards currently in the shoe with the shoe's shuffle
+    /// procedure.
     ///
-    /// Uses the rand crate's thread_rng for secure random shuffling.
-    /// This method only shuffles cards that haven't been dealt - it does
-    /// not affect discarded cards.
+    /// Uses the shoe's random number generator, so a seeded shoe always
+    /// shuffles the same way. This method only shuffles cards that haven't
+    /// been dealt - it does not affect discarded cards.
     ///
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::shoe::Shoe;
     /// let mut shoe = Shoe::new(1);
     /// shoe.shuffle(); // Randomizes order of cards
     /// ```
     pub fn shuffle(&mut self)  {
-        let mut rng = rand::rng();
-        self.cards.shuffle(&mut rng);
+        self.procedure.apply(&mut self.cards, 0, &mut self.rng);
+    }
+
+    /// Returns the procedure the shoe is shuffled with.
+    pub fn shuffle_procedure(&self) -> &ShuffleProcedure {
+        &self.procedure
+    }
+
+    /// Sets the procedure used for every later shuffle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use blackjack_engine::shoe::Shoe;
+    /// use blackjack_engine::shuffle::ShuffleProcedure;
+    /// let mut shoe = Shoe::with_seed(6, 1);
+    /// shoe.set_shuffle_procedure(ShuffleProcedure::zone_shuffle(4));
+    /// shoe.draw_card();
+    /// shoe.reshuffle();
+    /// assert_eq!(shoe.cards.len(), 312);
+    /// ```
+    pub fn set_shuffle_procedure(&mut self, procedure: ShuffleProcedure) {
+        self.procedure = procedure;
     }
 
     /// Prints all cards currently in the shoe for debugging purposes.
     ///
     /// Displays each card's rank and suit on a new line.
     pub fn print_deck(&self) {
-        for (_val, i) in self.cards.iter().enumerate() {
-            print!("{}", i.rank.to_string());
-            println!("{}", i.suit.to_string());
+        for i in self.cards.iter() {
+            print!("{}", i.rank);
+            println!("{}", i.suit);
         }
     }
 
     /// Draws a single card from the top of the shoe.
     ///
     /// The drawn card is removed from the available cards and added to
-    /// the discarded pile. Returns None if there are no cards left.
+    /// the cards in play. Returns None if there are no cards left.
     ///
     /// # Returns
     ///
@@ -96,6 +416,7 @@ impl Shoe {
     /// # Examples
     ///
     /// ```
+    /// # use blackjack_engine::shoe::Shoe;
     /// let mut shoe = Shoe::new(1);
     /// if let Some(card) = shoe.draw_card() {
     ///     println!("Drew: {}", card.to_string());
@@ -103,47 +424,26 @@ impl Shoe {
     /// ```
     pub fn draw_card(&mut self) -> Option<Card> {
         let card = self.cards.pop()?;
-        self.discarded.push(card.clone());
+        self.in_play.push(card.clone());
         Some(card)
     }
 
-    /// Ensures there are enough cards in the shoe for the current number of players.
-    ///
-    /// If there aren't enough cards remaining, creates and shuffles a new shoe.
-    /// This simulates a dealer getting a new shoe when the current one runs low,
-    /// which is standard casino practice.
-    ///
-    /// # Arguments
-    ///
-    /// * `num_players` - Current number of players at the table
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// let mut shoe = Shoe::new(6);
-    /// shoe.ensure_cards_for_players(3); // Ensures enough cards for 3 players
-    /// ```
-    ///
-    /// # Notes
-    ///
-    /// Calculates minimum cards needed as:
-    /// (num_players + 1 dealer) * 2 initial cards * 2 for potential additional draws
-    pub fn ensure_cards_for_players(&mut self, num_players: usize) {
-        // Calculate minimum cards needed:
-        // (num_players + 1 for dealer) * 2 initial cards * 2 for potential additional draws
-        let min_cards_needed = (num_players + 1) * 2 * 2;
-
-        if self.cards.len() < min_cards_needed {
-            // Create new shoe with calculated number of decks
-            let new_shoe = Shoe::new(self.number_of_decks);
-            self.cards = new_shoe.cards;
-            self.discarded.clear();
+    /// Shuffles the discard tray back into the shoe, leaving the top `buffer`
+    /// cards where they are.
+    fn return_discards(&mut self, buffer: usize) {
+        // Cards are drawn from the end, so the buffered cards are the last ones
+        let staged = self.cards.split_off(self.cards.len().saturating_sub(buffer));
+        self.cards.append(&mut self.discarded);
+        self.burned = 0;
+        // The machine randomizes on its own, whatever the dealer's procedure
+        self.cards.shuffle(&mut self.rng);
+        self.cards.extend(staged);
+    }
 
-            // Shuffle the new shoe
-            self.shuffle();
-            println!("Starting a new Shoe");
-            sleep(Duration::from_millis(2000));
-        }
+    /// Returns how many cards remain when the cut card comes out.
+    fn cut_card_position(num_decks: usize, percent: u8) -> usize {
+        let total = num_decks * 52;
+        total - total * usize::from(percent.min(100)) / 100
     }
 }
 
@@ -163,6 +463,138 @@ mod tests {
         assert_ne!(ordered_cards, shuffled_cards);
     }
 
+    #[test]
+    fn test_seeded_shuffle_is_repeatable() {
+        let mut first = Shoe::with_seed(6, 7);
+        let mut second = Shoe::with_seed(6, 7);
+        let mut other = Shoe::with_seed(6, 8);
+        for shoe in [&mut first, &mut second, &mut other] {
+            shoe.shuffle();
+            shoe.shuffle();
+        }
+
+        assert_eq!(first.cards, second.cards);
+        assert_ne!(first.cards, other.cards);
+    }
+
+    #[test]
+    fn test_stacked_shoe_deals_in_order() {
+        let mut shoe = Shoe::from_notation("As Kd\n8c  8h").unwrap();
+        assert!(shoe.has_cards_for(3));
+
+        let drawn: Vec<Card> = std::iter::from_fn(|| shoe.draw_card()).collect();
+        assert_eq!(drawn, vec![
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Hearts),
+        ]);
+    }
+
+    #[test]
+    fn test_stacked_shoe_rejects_bad_notation() {
+        let error = Shoe::from_notation("As 11d").err().unwrap();
+        assert_eq!(error.token, "11d");
+    }
+
+    #[test]
+    fn test_cut_card_at_penetration() {
+        let mut shoe = Shoe::new(2);
+        assert_eq!(shoe.cut_card, 26);
+
+        shoe.set_penetration(80);
+        for _ in 0..82 {
+            shoe.draw_card();
+        }
+        assert!(!shoe.cut_card_reached());
+        shoe.draw_card();
+        assert!(shoe.cut_card_reached());
+
+        shoe.reshuffle();
+        assert_eq!(shoe.cards.len(), 104);
+        assert!(!shoe.cut_card_reached());
+    }
+
+    #[test]
+    fn test_burned_cards_kept_apart_from_dealt_cards() {
+        let mut shoe = Shoe::from_notation("As Kd 8c 8h 2d 3d").unwrap();
+        shoe.burn(1);
+        shoe.draw_card();
+        shoe.draw_card();
+        shoe.discard_in_play();
+        shoe.draw_card();
+        // A later burn still goes face down at the front of the tray
+        shoe.burn(1);
+
+        let card = |notation: &str| notation.parse::<Card>().unwrap();
+        assert_eq!(shoe.burned_cards(), &[card("As"), card("2d")]);
+        assert_eq!(shoe.dealt_cards().cloned().collect::<Vec<_>>(), vec![card("Kd"), card("8c"), card("8h")]);
+        assert_eq!(shoe.in_play, vec![card("8h")]);
+        assert_eq!(shoe.discarded.len(), 4);
+
+        shoe.reshuffle();
+        assert_eq!(shoe.cards.len(), 6);
+        assert!(shoe.burned_cards().is_empty());
+        assert_eq!(shoe.dealt_cards().count(), 0);
+    }
+
+    #[test]
+    fn test_continuous_shoe_returns_discards_below_buffer() {
+        let mut shoe = Shoe::with_seed(1, 4);
+        shoe.set_mode(ShoeMode::Continuous { buffer: 20 });
+        shoe.shuffle();
+        shoe.burn(1);
+        let dealt: Vec<Card> = (0..5).filter_map(|_| shoe.draw_card()).collect();
+        let buffered = shoe.cards[shoe.cards.len() - 20..].to_vec();
+
+        shoe.discard_in_play();
+        assert_eq!(shoe.cards.len(), 52);
+        assert!(shoe.discarded.is_empty());
+        assert!(shoe.burned_cards().is_empty());
+        assert!(!shoe.cut_card_reached());
+
+        // The next 20 cards were already out of the machine, so the returned
+        // cards sit somewhere beneath them
+        assert_eq!(shoe.cards[32..], buffered[..]);
+        let mut returned = shoe.cards[..32].to_vec();
+        for card in dealt {
+            let position = returned.iter().position(|c| *c == card).unwrap();
+            returned.remove(position);
+        }
+        // What remains is the burned card and the 26 cards below the buffer
+        assert_eq!(returned.len(), 27);
+
+        // Rounds that draw past the buffer still get every card back
+        for _ in 0..3 {
+            assert_eq!((0..25).filter_map(|_| shoe.draw_card()).count(), 25);
+            assert_eq!(shoe.cards.len(), 27);
+            shoe.discard_in_play();
+            assert!(shoe.discarded.is_empty());
+            assert_eq!(shoe.cards.len(), 52);
+        }
+    }
+
+    #[test]
+    fn test_shuffle_procedure_chosen_per_shoe() {
+        let mut uniform = Shoe::with_seed(1, 6);
+        let mut hand_shuffled = Shoe::with_seed(1, 6);
+        hand_shuffled.set_shuffle_procedure(ShuffleProcedure::hand_shuffle());
+        assert_eq!(uniform.shuffle_procedure(), &ShuffleProcedure::uniform());
+
+        uniform.shuffle();
+        hand_shuffled.shuffle();
+        assert_ne!(uniform.cards, hand_shuffled.cards);
+        assert_eq!(hand_shuffled.cards.len(), 52);
+    }
+
+    #[test]
+    fn test_stacked_shoe_has_no_cut_card() {
+        let mut shoe = Shoe::from_notation("As Kd").unwrap();
+        shoe.draw_card();
+        shoe.draw_card();
+        assert!(!shoe.cut_card_reached());
+    }
+
     #[test]
     fn test_shoe_creation() {
         let num_decks = 2;
@@ -178,7 +610,7 @@ mod tests {
 
         assert!(card.is_some());
         assert_eq!(shoe.cards.len(), initial_count - 1);
-        assert_eq!(shoe.discarded.len(), 1);
+        assert_eq!(shoe.in_play.len(), 1);
     }
 
     #[test]
@@ -189,7 +621,7 @@ mod tests {
 
         assert!(shoe.draw_card().is_none());
         assert!(shoe.cards.is_empty());
-        assert_eq!(shoe.discarded.len(), 52); // Full deck in discard
+        assert_eq!(shoe.in_play.len(), 52); // Full deck dealt
     }
 
     #[test]
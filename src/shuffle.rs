@@ -0,0 +1,378 @@
+use std::fmt;
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+use crate::card::Card;
+
+/// Most cards a dealer takes from each zone for one riffle.
+const ZONE_GRAB: usize = 26;
+
+/// A single step of a physical shuffle, applied to a stack of cards.
+///
+/// Stacks are ordered bottom to top: the last card is the top of the stack
+/// and the first to be dealt.
+///
+/// * `Uniform` - A perfect random shuffle, as from a thorough wash
+/// * `Riffle` - The stack is cut roughly in half and the halves are riffled
+///   together. Cards fall in clumps rather than alternating perfectly,
+///   following the Gilbert-Shannon-Reeds model.
+/// * `Strip { packets }` - Packets of roughly equal size are stripped off the
+///   top one at a time onto a new pile, reversing their order. A box shuffle
+///   is a strip into four packets.
+/// * `Plug` - The unplayed cards, which sit at the bottom once the discards
+///   are gathered on top, are plugged into the discards in a few pieces at
+///   random depths
+/// * `Zone { piles }` - The stack is split into piles, and grabs from the
+///   outer piles are riffled together working inwards, as dealers do with
+///   multi-deck shoes too large to riffle at once
+/// * `Cut` - A single cut somewhere in the middle third of the stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShuffleStep {
+    Uniform,
+    Riffle,
+    Strip { packets: usize },
+    Plug,
+    Zone { piles: usize },
+    Cut,
+}
+
+impl ShuffleStep {
+    /// Applies the step to `cards`, where the bottom `unplayed` cards were
+    /// never dealt since the last shuffle. Only [`ShuffleStep::Plug`] uses
+    /// `unplayed`.
+    pub fn apply(&self, cards: &mut Vec<Card>, unplayed: usize, rng: &mut dyn RngCore) {
+        match *self {
+            ShuffleStep::Uniform => cards.shuffle(rng),
+            ShuffleStep::Riffle => {
+                let riffled = riffle(std::mem::take(cards), rng);
+                *cards = riffled;
+            }
+            ShuffleStep::Strip { packets } => strip(cards, packets, rng),
+            ShuffleStep::Plug => plug(cards, unplayed, rng),
+            ShuffleStep::Zone { piles } => zone(cards, piles, rng),
+            ShuffleStep::Cut => {
+                let len = cards.len();
+                if len > 2 {
+                    let point = rng.random_range(len / 3..=len * 2 / 3);
+                    cards.rotate_left(point);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ShuffleStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShuffleStep::Uniform => write!(f, "uniform"),
+            ShuffleStep::Riffle => write!(f, "riffle"),
+            ShuffleStep::Strip { packets } => write!(f, "strip into {}", packets),
+            ShuffleStep::Plug => write!(f, "plug"),
+            ShuffleStep::Zone { piles } => write!(f, "zone over {} piles", piles),
+            ShuffleStep::Cut => write!(f, "cut"),
+        }
+    }
+}
+
+/// A named sequence of [`ShuffleStep`]s that makes up a dealer's shuffle.
+///
+/// Each [`Shoe`](crate::shoe::Shoe) shuffles with its own procedure, which
+/// is [`ShuffleProcedure::uniform`] unless set otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use blackjack_engine::shoe::Shoe;
+/// use blackjack_engine::shuffle::{ShuffleProcedure, ShuffleStep};
+/// let procedure = ShuffleProcedure::new(
+///     "Riffle and cut".to_string(),
+///     vec![ShuffleStep::Riffle, ShuffleStep::Riffle, ShuffleStep::Cut],
+/// );
+/// let mut shoe = Shoe::with_seed(1, 42);
+/// shoe.set_shuffle_procedure(procedure);
+/// shoe.shuffle();
+/// assert_eq!(shoe.cards.len(), 52);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShuffleProcedure {
+    /// Name used to tell procedures apart in results
+    pub name: String,
+    /// Steps applied in order
+    pub steps: Vec<ShuffleStep>,
+}
+
+impl ShuffleProcedure {
+    /// Creates a procedure that applies `steps` in order.
+    pub fn new(name: String, steps: Vec<ShuffleStep>) -> Self {
+        ShuffleProcedure { name, steps }
+    }
+
+    /// A single perfect random shuffle. This is the default procedure.
+    pub fn uniform() -> Self {
+        ShuffleProcedure::new("Uniform".to_string(), vec![ShuffleStep::Uniform])
+    }
+
+    /// The classic hand shuffle for one or two decks: riffle, riffle, strip,
+    /// riffle and cut.
+    pub fn hand_shuffle() -> Self {
+        ShuffleProcedure::new(
+            "Riffle-riffle-strip-riffle-cut".to_string(),
+            vec![
+                ShuffleStep::Riffle,
+                ShuffleStep::Riffle,
+                ShuffleStep::Strip { packets: 4 },
+                ShuffleStep::Riffle,
+                ShuffleStep::Cut,
+            ],
+        )
+    }
+
+    /// A typical multi-deck shoe shuffle: the unplayed cards are plugged
+    /// into the discards, then the stack is zone shuffled over `piles`
+    /// piles, stripped, zone shuffled again and cut.
+    pub fn zone_shuffle(piles: usize) -> Self {
+        ShuffleProcedure::new(
+            format!("Plug and {}-pile zone", piles),
+            vec![
+                ShuffleStep::Plug,
+                ShuffleStep::Zone { piles },
+                ShuffleStep::Strip { packets: 4 },
+                ShuffleStep::Zone { piles },
+                ShuffleStep::Cut,
+            ],
+        )
+    }
+
+    /// Applies every step in order to `cards`, where the bottom `unplayed`
+    /// cards were never dealt since the last shuffle.
+    pub fn apply(&self, cards: &mut Vec<Card>, unplayed: usize, rng: &mut dyn RngCore) {
+        for step in self.steps.iter() {
+            step.apply(cards, unplayed, rng);
+        }
+    }
+}
+
+impl Default for ShuffleProcedure {
+    fn default() -> Self {
+        ShuffleProcedure::uniform()
+    }
+}
+
+impl fmt::Display for ShuffleProcedure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Cuts `cards` near the middle and riffles the two halves together.
+///
+/// The cut point is binomially distributed and each card falls from a half
+/// with probability proportional to the cards left in it, so the halves
+/// interleave in uneven clumps.
+fn riffle(cards: Vec<Card>, rng: &mut dyn RngCore) -> Vec<Card> {
+    let cut = (0..cards.len()).filter(|_| rng.random_bool(0.5)).count();
+    let mut top = cards;
+    let bottom: Vec<Card> = top.drain(..cut).collect();
+    merge(bottom, top, rng)
+}
+
+/// Interleaves two packets from the bottom up, keeping the order within each.
+fn merge(left: Vec<Card>, right: Vec<Card>, rng: &mut dyn RngCore) -> Vec<Card> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter();
+    let mut right = right.into_iter();
+    let (mut left_count, mut right_count) = (left.len(), right.len());
+    while left_count > 0 && right_count > 0 {
+        if rng.random_range(0..left_count + right_count) < left_count {
+            merged.extend(left.next());
+            left_count -= 1;
+        } else {
+            merged.extend(right.next());
+            right_count -= 1;
+        }
+    }
+    merged.extend(left);
+    merged.extend(right);
+    merged
+}
+
+/// Strips `packets` packets off the top of `cards` onto a new pile. Fewer
+/// than two packets leave the stack as it is.
+fn strip(cards: &mut Vec<Card>, packets: usize, rng: &mut dyn RngCore) {
+    if packets < 2 {
+        return;
+    }
+    let mut stripped = Vec::with_capacity(cards.len());
+    for remaining_packets in (1..=packets).rev() {
+        if cards.is_empty() {
+            break;
+        }
+        let size = if remaining_packets == 1 {
+            cards.len()
+        } else {
+            let even = cards.len() / remaining_packets;
+            rng.random_range(even * 3 / 4..=even * 5 / 4).clamp(1, cards.len())
+        };
+        // Each packet lands on top of the ones stripped before it
+        let packet = cards.split_off(cards.len() - size);
+        stripped.extend(packet);
+    }
+    *cards = stripped;
+}
+
+/// Plugs the bottom `unplayed` cards into the rest of the stack in a few
+/// pieces at random depths.
+fn plug(cards: &mut Vec<Card>, unplayed: usize, rng: &mut dyn RngCore) {
+    if unplayed == 0 || unplayed >= cards.len() {
+        return;
+    }
+    let mut slug: Vec<Card> = cards.drain(..unplayed).collect();
+    let pieces = unplayed.div_ceil(ZONE_GRAB).clamp(1, 4);
+    for remaining_pieces in (1..=pieces).rev() {
+        let size = slug.len() / remaining_pieces;
+        let piece: Vec<Card> = slug.drain(..size).collect();
+        let depth = rng.random_range(0..=cards.len());
+        cards.splice(depth..depth, piece);
+    }
+}
+
+/// Splits `cards` into `piles` piles and riffles grabs from the outer piles
+/// together, working inwards. A middle pile left over is riffled on its own.
+fn zone(cards: &mut Vec<Card>, piles: usize, rng: &mut dyn RngCore) {
+    if piles < 2 || cards.len() < piles {
+        let riffled = riffle(std::mem::take(cards), rng);
+        *cards = riffled;
+        return;
+    }
+    let size = cards.len().div_ceil(piles);
+    let mut zones: Vec<Vec<Card>> = cards.chunks(size).map(|chunk| chunk.to_vec()).collect();
+    cards.clear();
+
+    let (mut left, mut right) = (0, zones.len() - 1);
+    while left < right {
+        while !zones[left].is_empty() || !zones[right].is_empty() {
+            let left_grab = zones[left].len().saturating_sub(ZONE_GRAB);
+            let right_grab = zones[right].len().saturating_sub(ZONE_GRAB);
+            let left_cards = zones[left].split_off(left_grab);
+            let right_cards = zones[right].split_off(right_grab);
+            cards.extend(merge(left_cards, right_cards, rng));
+        }
+        left += 1;
+        right -= 1;
+    }
+    if left == right {
+        let middle = std::mem::take(&mut zones[left]);
+        cards.extend(riffle(middle, rng));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use strum::IntoEnumIterator;
+    use crate::card::{Rank, Suit};
+    use super::*;
+
+    fn deck(num_decks: usize) -> Vec<Card> {
+        (0..num_decks)
+            .flat_map(|_| Rank::iter().flat_map(|rank| Suit::iter().map(move |suit| Card::new(rank.clone(), suit))))
+            .collect()
+    }
+
+    fn sorted_names(cards: &[Card]) -> Vec<String> {
+        let mut names: Vec<String> = cards.iter().map(|card| card.to_string()).collect();
+        names.sort();
+        names
+    }
+
+    /// Counts the rising sequences of a shuffled single deck, which is how
+    /// many riffles of an ordered deck it takes to produce
+    fn rising_sequences(original: &[Card], shuffled: &[Card]) -> usize {
+        let positions: Vec<usize> = original.iter()
+            .map(|card| shuffled.iter().position(|c| c == card).unwrap())
+            .collect();
+        1 + positions.windows(2).filter(|pair| pair[1] < pair[0]).count()
+    }
+
+    #[test]
+    fn test_steps_keep_every_card() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let steps = [
+            ShuffleStep::Uniform, ShuffleStep::Riffle, ShuffleStep::Strip { packets: 5 },
+            ShuffleStep::Plug, ShuffleStep::Zone { piles: 3 }, ShuffleStep::Cut,
+        ];
+        for step in steps {
+            let mut cards = deck(2);
+            step.apply(&mut cards, 40, &mut rng);
+            assert_eq!(sorted_names(&cards), sorted_names(&deck(2)), "{}", step);
+        }
+    }
+
+    #[test]
+    fn test_riffle_interleaves_two_packets() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let original = deck(1);
+        let mut cards = original.clone();
+        ShuffleStep::Riffle.apply(&mut cards, 0, &mut rng);
+
+        assert_ne!(cards, original);
+        assert_eq!(rising_sequences(&original, &cards), 2);
+    }
+
+    #[test]
+    fn test_strip_reverses_packets() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let original = deck(1);
+        let mut cards = original.clone();
+        ShuffleStep::Strip { packets: 4 }.apply(&mut cards, 0, &mut rng);
+
+        // The top packet is stripped first and ends up at the bottom
+        let position = |card: &Card| cards.iter().position(|c| c == card).unwrap();
+        assert!(position(original.last().unwrap()) < 26);
+        assert!(position(&original[0]) >= 26);
+    }
+
+    #[test]
+    fn test_strip_into_one_packet_keeps_the_stack() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for packets in [0, 1] {
+            let mut cards = deck(1);
+            ShuffleStep::Strip { packets }.apply(&mut cards, 0, &mut rng);
+            assert_eq!(cards.len(), 52);
+            assert_eq!(cards, deck(1));
+        }
+    }
+
+    #[test]
+    fn test_plug_spreads_the_unplayed_cards() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let original = deck(1);
+        let mut cards = original.clone();
+        ShuffleStep::Plug.apply(&mut cards, 26, &mut rng);
+
+        // The discards keep their order, with the unplayed cards pushed in
+        let discards: Vec<&Card> = cards.iter().filter(|card| !original[..26].contains(card)).collect();
+        assert_eq!(discards, original[26..].iter().collect::<Vec<_>>());
+        assert_ne!(cards[..26], original[..26]);
+    }
+
+    #[test]
+    fn test_procedures_are_repeatable_with_a_seed() {
+        for procedure in [ShuffleProcedure::hand_shuffle(), ShuffleProcedure::zone_shuffle(4)] {
+            let shuffle = |seed: u64| {
+                let mut cards = deck(6);
+                procedure.apply(&mut cards, 78, &mut StdRng::seed_from_u64(seed));
+                cards
+            };
+            assert_eq!(shuffle(5), shuffle(5));
+            assert_ne!(shuffle(5), shuffle(6));
+            assert_eq!(sorted_names(&shuffle(5)), sorted_names(&deck(6)));
+        }
+    }
+
+    #[test]
+    fn test_default_procedure_is_uniform() {
+        assert_eq!(ShuffleProcedure::default(), ShuffleProcedure::uniform());
+        assert_eq!(ShuffleProcedure::zone_shuffle(3).to_string(), "Plug and 3-pile zone");
+    }
+}